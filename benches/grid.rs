@@ -0,0 +1,70 @@
+//! Compares the dense and sparse storages of `Grid` on the days that use
+//! fully populated grids: each day is built from its grid, stored one way
+//! or the other, and its parts are timed. Run with `cargo bench --bench grid`.
+
+use std::fs;
+use std::time::{Duration, Instant};
+
+use advent_2022::bench::Stats;
+use advent_2022::grid::Grid;
+use advent_2022::progress::Progress;
+use advent_2022::utils::{self, Day, ParseError};
+use advent_2022::year2022::{day12::Day12, day24::Day24, day8::Day8};
+
+/// Builds a day from its input, keeping its grid sparse if `true`.
+type Build = fn(&str, bool) -> Result<Box<dyn Day>, ParseError>;
+
+/// The grid of the lines of `input`, stored sparsely if `sparse`.
+fn grid<T>(input: &str, sparse: bool) -> Result<Grid<T>, ParseError>
+where
+    T: std::str::FromStr,
+    T::Err: 'static,
+{
+    let grid = Grid::from(&utils::lines(input))?;
+    Ok(if sparse { grid.into_sparse() } else { grid })
+}
+
+const DAYS: [(u8, Build); 3] = [
+    (8, |input, sparse| Day8::from_grid(grid(input, sparse)?)),
+    (12, |input, sparse| Day12::from_grid(grid(input, sparse)?)),
+    (24, |input, sparse| Day24::from_grid(grid(input, sparse)?)),
+];
+const RUNS: usize = 20;
+
+/// The median time of building the day, then of each of its parts.
+fn time(build: Build, input: &str, sparse: bool) -> [Duration; 3] {
+    let mut samples = [vec![], vec![], vec![]];
+    for _ in 0..RUNS {
+        let before = Instant::now();
+        let day = build(input, sparse).unwrap();
+        samples[0].push(before.elapsed());
+        for (part, samples) in ['a', 'b'].into_iter().zip(&mut samples[1..]) {
+            samples.push(day.solution(part, &Progress::disabled()).elapsed);
+        }
+    }
+    samples.map(|samples| Stats::from_samples(&samples).median)
+}
+
+fn main() {
+    println!("Solving the days, median of {} runs", RUNS);
+    println!("Day | Phase |      Dense |     Sparse | Speedup");
+    println!("------------------------------------------------");
+    for (day, build) in DAYS {
+        let path = format!("data/2022/day{}.txt", day);
+        let Ok(input) = fs::read_to_string(&path) else {
+            println!("{:>3} | no input in {}", day, path);
+            continue;
+        };
+        let (dense, sparse) = (time(build, &input, false), time(build, &input, true));
+        for (phase, (dense, sparse)) in ["build", "a", "b"].iter().zip(dense.iter().zip(sparse)) {
+            println!(
+                "{:>3} | {:<5} | {:>10} | {:>10} | {:>6.1}x",
+                day,
+                phase,
+                format!("{:.2?}", dense),
+                format!("{:.2?}", sparse),
+                sparse.as_secs_f64() / dense.as_secs_f64()
+            );
+        }
+    }
+}
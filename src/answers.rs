@@ -0,0 +1,124 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::utils::Solution;
+
+/// Expected answers live next to the input, e.g.
+/// `data/2022/day10-test.answers` for `data/2022/day10-test.txt`. Each part
+/// starts with a `[a]` or `[b]` header line, followed by the answer, which
+/// can span several lines.
+pub fn answers_path(input: &Path) -> PathBuf {
+    input.with_extension("answers")
+}
+
+pub fn parse_answers(content: &str) -> Result<HashMap<char, String>, String> {
+    let mut answers = HashMap::new();
+    let mut current: Option<(char, Vec<&str>)> = None;
+    for (i, line) in content.lines().enumerate() {
+        if let Some(part) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let part = match part {
+                "a" => 'a',
+                "b" => 'b',
+                _ => return Err(format!("line {}: unknown part [{}]", i + 1, part)),
+            };
+            if let Some((part, lines)) = current.take() {
+                answers.insert(part, lines.join("\n"));
+            }
+            current = Some((part, vec![]));
+        } else if let Some((_, lines)) = current.as_mut() {
+            lines.push(line);
+        } else if !line.trim().is_empty() {
+            return Err(format!("line {}: expected a [a] or [b] header", i + 1));
+        }
+    }
+    if let Some((part, lines)) = current {
+        answers.insert(part, lines.join("\n"));
+    }
+    for answer in answers.values_mut() {
+        answer.truncate(answer.trim_end().len());
+    }
+    Ok(answers)
+}
+
+pub fn read_answers(input: &Path) -> io::Result<HashMap<char, String>> {
+    let path = answers_path(input);
+    let content = fs::read_to_string(&path)?;
+    parse_answers(&content).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
+
+pub enum Check {
+    Ok,
+    Missing,
+    Mismatch { expected: String, actual: String },
+    Failed(String),
+}
+
+pub fn check(solution: &Solution, expected: &HashMap<char, String>) -> Check {
+    let actual = match &solution.answer {
+        Ok(answer) => answer.to_string(),
+        Err(e) => return Check::Failed(e.clone()),
+    };
+    let actual = actual.trim_end();
+    match expected.get(&solution.part) {
+        None => Check::Missing,
+        Some(expected) if expected == actual => Check::Ok,
+        Some(expected) => Check::Mismatch {
+            expected: expected.clone(),
+            actual: actual.to_owned(),
+        },
+    }
+}
+
+/// Line-based diff, good enough to spot which rows of a multi-line answer
+/// (e.g. day 10's CRT output) differ.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected_lines = expected.lines().collect::<Vec<_>>();
+    let actual_lines = actual.lines().collect::<Vec<_>>();
+    let mut result = vec![];
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => result.push(format!("  {}", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    result.push(format!("- {}", e));
+                }
+                if let Some(a) = a {
+                    result.push(format!("+ {}", a));
+                }
+            }
+        }
+    }
+    result.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, parse_answers};
+
+    #[test]
+    fn parse_multiline_answers() {
+        let answers = parse_answers("[a]\n13140\n[b]\n##..\n#..#\n\n").unwrap();
+        assert_eq!(answers[&'a'], "13140");
+        assert_eq!(answers[&'b'], "##..\n#..#");
+    }
+
+    #[test]
+    fn parse_invalid_answers() {
+        assert!(parse_answers("42\n").is_err());
+        assert!(parse_answers("[c]\n42\n").is_err());
+    }
+
+    #[test]
+    fn diff_lines() {
+        assert_eq!(diff("ab\ncd", "ab\nce"), "  ab\n- cd\n+ ce");
+        assert_eq!(diff("1", ""), "- 1");
+    }
+}
@@ -0,0 +1,117 @@
+use std::time::{Duration, Instant};
+
+use crate::params::{Param, Params};
+use crate::progress::Progress;
+use crate::{check_parts, find_day, Error};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        Stats {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / n as u32,
+            max: sorted[n - 1],
+        }
+    }
+}
+
+/// Timings of one phase: `"parse"`, or the part (`"a"`, `"b"`).
+pub struct Phase {
+    pub name: String,
+    pub stats: Stats,
+}
+
+/// Parses `input` and solves `parts` `runs` times, timing parsing and
+/// each part separately.
+pub fn bench(
+    year: u16,
+    day: u8,
+    input: &str,
+    params: &[Param],
+    parts: &[char],
+    runs: usize,
+) -> Result<Vec<Phase>, Error> {
+    let info = find_day(year, day)?;
+    let params = Params::resolve(info.params, params)?;
+    check_parts(parts)?;
+    let mut parse_samples = vec![];
+    let mut part_samples = vec![vec![]; parts.len()];
+    for _ in 0..runs {
+        let before = Instant::now();
+        let solver = (info.build)(input, &params)?;
+        parse_samples.push(before.elapsed());
+        for (part, samples) in parts.iter().zip(part_samples.iter_mut()) {
+            samples.push(solver.solution(*part, &Progress::disabled()).elapsed);
+        }
+    }
+
+    let mut phases = vec![Phase {
+        name: "parse".to_owned(),
+        stats: Stats::from_samples(&parse_samples),
+    }];
+    phases.extend(parts.iter().zip(part_samples).map(|(part, samples)| Phase {
+        name: part.to_string(),
+        stats: Stats::from_samples(&samples),
+    }));
+    Ok(phases)
+}
+
+pub fn print_phases(phases: &[Phase]) {
+    println!(
+        "{:<5} | {:>10} | {:>10} | {:>10} | {:>10}",
+        "Phase", "Min", "Median", "Mean", "Max"
+    );
+    println!("{}", "-".repeat(5 + 4 * 13));
+    for phase in phases {
+        let stats = &phase.stats;
+        println!(
+            "{:<5} | {:>10} | {:>10} | {:>10} | {:>10}",
+            phase.name,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.max),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{bench, Stats};
+
+    #[test]
+    fn stats_from_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.max, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn bench_phases() {
+        let phases = bench(2022, 1, "1000\n\n2000\n", &[], &['a', 'b'], 3).unwrap();
+        let names = phases.iter().map(|p| p.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["parse", "a", "b"]);
+    }
+}
@@ -4,6 +4,8 @@ use std::{
     str::FromStr,
 };
 
+use crate::utils::{self, ParseError};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Point {
     pub x: i64,
@@ -37,13 +39,43 @@ impl fmt::Display for Point {
     }
 }
 
+/// Where the cells of a grid are stored.
+#[derive(Debug, Clone)]
+enum Cells<T> {
+    /// Every point of the `width` x `height` rectangle, row by row, for
+    /// fully populated grids: a lookup is an index computation.
+    Dense(Vec<Option<T>>),
+    /// Only the points that hold a value, for grids that grow in any
+    /// direction or hold few values.
+    Sparse(HashMap<Point, T>),
+}
+
+/// How the points of a grid connect at its edges, for neighbor queries and
+/// steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    /// Only the points of the `width` x `height` rectangle exist.
+    Bounded,
+    /// Every point exists, for grids that grow in any direction.
+    Unbounded,
+    /// Leaving the rectangle by an edge enters it by the opposite one.
+    Torus,
+}
+
 #[derive(Debug, Clone)]
 pub struct Grid<T> {
     pub width: u64,
     pub height: u64,
     pub going_down: bool,
-    pub cells: HashMap<Point, T>,
+    cells: Cells<T>,
     pub empty_cell: char,
+    pub topology: Topology,
+}
+
+/// Point stored at `index` in the dense storage of a grid `width` wide.
+fn dense_point(index: usize, width: u64) -> Point {
+    let width = width as usize;
+    Point::new((index % width) as i64, (index / width) as i64)
 }
 
 pub fn get_neighbors(point: &Point, include_diagonals: bool) -> HashSet<Point> {
@@ -62,53 +94,271 @@ pub fn get_neighbors(point: &Point, include_diagonals: bool) -> HashSet<Point> {
 }
 
 impl<T> Grid<T> {
+    /// A sparse and unbounded grid, which accepts values at any point.
     pub fn new(width: u64, height: u64, going_down: bool) -> Self {
         Self {
             width,
             height,
             going_down,
-            cells: HashMap::new(),
+            cells: Cells::Sparse(HashMap::new()),
+            empty_cell: '.',
+            topology: Topology::Unbounded,
+        }
+    }
+
+    /// A dense and bounded grid, which only accepts values inside its bounds.
+    pub fn dense(width: u64, height: u64, going_down: bool) -> Self {
+        let mut cells = Vec::new();
+        cells.resize_with((width * height) as usize, || None);
+        Self {
+            width,
+            height,
+            going_down,
+            cells: Cells::Dense(cells),
             empty_cell: '.',
+            topology: Topology::Bounded,
         }
     }
 
+    pub fn is_dense(&self) -> bool {
+        matches!(self.cells, Cells::Dense(_))
+    }
+
+    /// Index of `point` in the dense storage, `None` outside the bounds.
+    fn index(&self, point: &Point) -> Option<usize> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        ((0..width).contains(&point.x) && (0..height).contains(&point.y))
+            .then(|| (point.y * width + point.x) as usize)
+    }
+
+    /// Whether `point` is inside the `width` x `height` rectangle.
+    pub fn in_bounds(&self, point: &Point) -> bool {
+        self.index(point).is_some()
+    }
+
+    /// The point `point` stands for in the topology of the grid: itself if
+    /// it exists, its wrapped position on a torus, `None` outside a bounded
+    /// grid.
+    pub fn wrap(&self, point: Point) -> Option<Point> {
+        match self.topology {
+            Topology::Bounded => self.in_bounds(&point).then_some(point),
+            Topology::Unbounded => Some(point),
+            Topology::Torus => Some(Point::new(
+                point.x.rem_euclid(self.width as i64),
+                point.y.rem_euclid(self.height as i64),
+            )),
+        }
+    }
+
+    /// The point reached by moving from `point` by `direction`, `None` when
+    /// it leaves a bounded grid.
+    pub fn step(&self, point: Point, direction: Point) -> Option<Point> {
+        self.wrap(point + direction)
+    }
+
+    /// The neighbors of `point` that exist in the topology of the grid.
     pub fn get_neighbors(&self, point: &Point, include_diagonals: bool) -> HashSet<Point> {
-        get_neighbors(point, include_diagonals).into_iter().filter(|p| {
-            p.x < self.width as i64 && p.y < self.height as i64
-        }).collect()
+        get_neighbors(point, include_diagonals)
+            .into_iter()
+            .filter_map(|p| self.wrap(p))
+            .filter(|p| p != point)
+            .collect()
     }
 
     pub fn get(&self, point: &Point) -> Option<&T> {
-        self.cells.get(point)
+        match &self.cells {
+            Cells::Dense(cells) => cells[self.index(point)?].as_ref(),
+            Cells::Sparse(cells) => cells.get(point),
+        }
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        let index = self.index(point);
+        match &mut self.cells {
+            Cells::Dense(cells) => cells[index?].as_mut(),
+            Cells::Sparse(cells) => cells.get_mut(point),
+        }
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.get(point).is_some()
+    }
+
+    /// Stores `value` at `point`, returning the previous value. Panics if
+    /// the grid is dense and `point` is outside its bounds.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        let index = self.index(&point);
+        match &mut self.cells {
+            Cells::Dense(cells) => {
+                let index = index.unwrap_or_else(|| {
+                    panic!(
+                        "{} is outside the {}x{} grid",
+                        point, self.width, self.height
+                    )
+                });
+                cells[index].replace(value)
+            }
+            Cells::Sparse(cells) => cells.insert(point, value),
+        }
+    }
+
+    pub fn remove(&mut self, point: &Point) -> Option<T> {
+        let index = self.index(point);
+        match &mut self.cells {
+            Cells::Dense(cells) => cells[index?].take(),
+            Cells::Sparse(cells) => cells.remove(point),
+        }
+    }
+
+    /// Keeps only the cells for which `f` returns true.
+    pub fn retain<F: FnMut(&Point, &mut T) -> bool>(&mut self, mut f: F) {
+        let width = self.width;
+        match &mut self.cells {
+            Cells::Dense(cells) => {
+                for (i, cell) in cells.iter_mut().enumerate() {
+                    let point = dense_point(i, width);
+                    if cell.as_mut().is_some_and(|value| !f(&point, value)) {
+                        *cell = None;
+                    }
+                }
+            }
+            Cells::Sparse(cells) => cells.retain(f),
+        }
+    }
+
+    /// The points holding a value, with their values. The dense storage
+    /// iterates row by row, the sparse one in no particular order.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = (Point, &T)> + '_> {
+        match &self.cells {
+            Cells::Dense(cells) => Box::new(
+                cells
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, cell)| Some((dense_point(i, self.width), cell.as_ref()?))),
+            ),
+            Cells::Sparse(cells) => Box::new(cells.iter().map(|(p, value)| (*p, value))),
+        }
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Number of points holding a value.
+    pub fn len(&self) -> usize {
+        match &self.cells {
+            Cells::Dense(cells) => cells.iter().filter(|cell| cell.is_some()).count(),
+            Cells::Sparse(cells) => cells.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The same grid, stored sparsely.
+    pub fn into_sparse(self) -> Self {
+        let cells = match self.cells {
+            Cells::Dense(cells) => {
+                let cells = cells.into_iter().enumerate();
+                let cells = cells.filter_map(|(i, cell)| Some((dense_point(i, self.width), cell?)));
+                Cells::Sparse(cells.collect())
+            }
+            cells => cells,
+        };
+        Self { cells, ..self }
+    }
+
+    /// The same grid, stored densely. Panics if a value is outside the bounds.
+    pub fn into_dense(self) -> Self {
+        match self.cells {
+            Cells::Dense(_) => self,
+            Cells::Sparse(cells) => {
+                let mut grid = Self::dense(self.width, self.height, self.going_down);
+                grid.empty_cell = self.empty_cell;
+                grid.topology = self.topology;
+                for (point, value) in cells {
+                    grid.insert(point, value);
+                }
+                grid
+            }
+        }
     }
 }
 
-impl<T> Grid<T>
-where
-    T: FromStr,
-    <T as std::str::FromStr>::Err: std::fmt::Debug,
-{
-    pub fn from(lines: &[String]) -> Self {
-        let mut cells = HashMap::new();
-        let height = lines.len() as u64;
-        let mut width = 0;
+/// The width and height of the grid made of the characters of `lines`.
+fn lines_size(lines: &[String]) -> (u64, u64) {
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    (width as u64, lines.len() as u64)
+}
+
+impl<T> Grid<T> {
+    /// A dense grid holding the values `f` maps the characters of the lines
+    /// to, given their position. Characters mapped to `None` are left empty.
+    pub fn from_with<F>(lines: &[String], mut f: F) -> Self
+    where
+        F: FnMut(Point, char) -> Option<T>,
+    {
+        let (width, height) = lines_size(lines);
+        let grid = Self::dense(width, height, true);
+        match grid.fill(lines, |point, c| Ok(f(point, c))) {
+            Ok(grid) => grid,
+            Err(e) => unreachable!("{}", e),
+        }
+    }
+
+    /// Like `from_with`, with a mapper that can reject characters. Its
+    /// errors are located at the line and column of the character.
+    pub fn try_from_with<F>(lines: &[String], f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(Point, char) -> Result<Option<T>, ParseError>,
+    {
+        let (width, height) = lines_size(lines);
+        Self::dense(width, height, true).fill(lines, f)
+    }
+
+    /// Like `from_with`, stored sparsely, for grids holding few values or
+    /// growing past the lines.
+    pub fn sparse_from_with<F>(lines: &[String], mut f: F) -> Self
+    where
+        F: FnMut(Point, char) -> Option<T>,
+    {
+        let (width, height) = lines_size(lines);
+        let grid = Self::new(width, height, true);
+        match grid.fill(lines, |point, c| Ok(f(point, c))) {
+            Ok(grid) => grid,
+            Err(e) => unreachable!("{}", e),
+        }
+    }
+
+    fn fill<F>(mut self, lines: &[String], mut f: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(Point, char) -> Result<Option<T>, ParseError>,
+    {
         for (y, line) in lines.iter().enumerate() {
-            width = width.max(line.len() as u64);
             for (x, c) in line.chars().enumerate() {
-                cells.insert(
-                    Point::new(x as i64, y as i64),
-                    c.to_string().parse().unwrap(),
-                );
+                let point = Point::new(x as i64, y as i64);
+                let value = f(point, c).map_err(|e| e.at_column(x + 1).at_line(y + 1))?;
+                if let Some(value) = value {
+                    self.insert(point, value);
+                }
             }
         }
+        Ok(self)
+    }
+}
 
-        Self {
-            width,
-            height,
-            cells,
-            going_down: true,
-            empty_cell: '.',
-        }
+impl<T> Grid<T>
+where
+    T: FromStr,
+    T::Err: 'static,
+{
+    /// A dense grid holding a value for every character of the lines.
+    pub fn from(lines: &[String]) -> Result<Self, ParseError> {
+        Self::try_from_with(lines, |point, c| {
+            let column = point.x as usize + 1;
+            utils::parse_at(c.encode_utf8(&mut [0; 4]), column).map(Some)
+        })
     }
 }
 
@@ -119,7 +369,7 @@ impl<T: fmt::Display + Clone> fmt::Display for Grid<T> {
                 y = self.height - y - 1;
             }
             for x in 0..self.width {
-                let c = self.cells.get(&Point::new(x as i64, y as i64)).cloned();
+                let c = self.get(&Point::new(x as i64, y as i64)).cloned();
                 if let Some(c) = c {
                     write!(f, "{}", c)?;
                 } else {
@@ -136,7 +386,8 @@ impl<T: fmt::Display + Clone> fmt::Display for Grid<T> {
 mod tests {
     use std::collections::HashSet;
 
-    use super::{Grid, Point};
+    use super::{Grid, Point, Topology};
+    use crate::utils::ParseError;
 
     fn get_grid() -> Grid<char> {
         Grid::from(&[
@@ -145,6 +396,7 @@ mod tests {
             String::from("ijkl"),
             String::from("mnop"),
         ])
+        .unwrap()
     }
 
     #[test]
@@ -179,6 +431,104 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_neighbors_topology() {
+        let mut grid = get_grid();
+        assert_eq!(
+            grid.get_neighbors(&Point::new(0, 0), true),
+            HashSet::from([Point::new(1, 0), Point::new(0, 1), Point::new(1, 1)])
+        );
+        grid.topology = Topology::Torus;
+        assert_eq!(
+            grid.get_neighbors(&Point::new(0, 0), false),
+            HashSet::from([
+                Point::new(1, 0),
+                Point::new(3, 0),
+                Point::new(0, 1),
+                Point::new(0, 3),
+            ])
+        );
+        grid.topology = Topology::Unbounded;
+        assert_eq!(grid.get_neighbors(&Point::new(0, 0), true).len(), 8);
+    }
+
+    #[test]
+    fn step_in_topology() {
+        let mut grid = get_grid();
+        let left = Point::new(-1, 0);
+        assert_eq!(grid.step(Point::new(1, 2), left), Some(Point::new(0, 2)));
+        assert_eq!(grid.step(Point::new(0, 2), left), None);
+        grid.topology = Topology::Torus;
+        assert_eq!(grid.step(Point::new(0, 2), left), Some(Point::new(3, 2)));
+        assert_eq!(
+            grid.step(Point::new(3, 3), Point::new(1, 1)),
+            Some(Point::new(0, 0))
+        );
+        let sparse = Grid::<char>::new(4, 4, true);
+        assert_eq!(sparse.step(Point::new(0, 2), left), Some(Point::new(-1, 2)));
+    }
+
+    #[test]
+    fn from_invalid_cell() {
+        let err = Grid::<u8>::from(&[String::from("123"), String::from("4x6")]).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn from_with_mapper() {
+        let lines = [String::from("#.>"), String::from(".v#")];
+        let grid = Grid::from_with(&lines, |_, c| (c != '.').then_some(c));
+        assert!(grid.is_dense());
+        assert_eq!(grid.len(), 4);
+        assert_eq!(grid.to_string(), "#.>\n.v#\n");
+        let walls = Grid::sparse_from_with(&lines, |p, c| (c == '#').then_some(p.x));
+        assert!(!walls.is_dense());
+        assert_eq!(walls.get(&Point::new(2, 1)), Some(&2));
+        assert_eq!(walls.len(), 2);
+        let err = Grid::<char>::try_from_with(&lines, |_, c| match c {
+            'v' => Err(ParseError::new("a wall", "v")),
+            c => Ok(Some(c)),
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn dense_and_sparse_storage() {
+        let dense = get_grid();
+        assert!(dense.is_dense());
+        assert_eq!(dense.get(&Point::new(1, 2)), Some(&'j'));
+        assert_eq!(dense.get(&Point::new(-1, 2)), None);
+        assert_eq!(dense.get(&Point::new(4, 0)), None);
+        let mut sparse = dense.clone().into_sparse();
+        assert!(!sparse.is_dense());
+        assert_eq!(sparse.len(), 16);
+        assert_eq!(sparse.to_string(), dense.to_string());
+        sparse.insert(Point::new(-3, 7), 'z');
+        assert_eq!(sparse.get(&Point::new(-3, 7)), Some(&'z'));
+        sparse.remove(&Point::new(-3, 7));
+        assert_eq!(sparse.into_dense().to_string(), dense.to_string());
+    }
+
+    #[test]
+    fn update_dense_cells() {
+        let mut grid = get_grid();
+        grid.retain(|p, _| p.x != p.y);
+        assert_eq!(grid.len(), 12);
+        assert!(!grid.contains(&Point::new(2, 2)));
+        assert_eq!(grid.insert(Point::new(2, 2), 'K'), None);
+        *grid.get_mut(&Point::new(0, 1)).unwrap() = 'E';
+        assert_eq!(grid.to_string(), ".bcd\nE.gh\nijKl\nmno.\n");
+        let points = grid.iter().map(|(p, _)| p).take(2).collect::<Vec<_>>();
+        assert_eq!(points, [Point::new(1, 0), Point::new(2, 0)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_outside_dense_grid() {
+        get_grid().insert(Point::new(4, 0), 'x');
+    }
+
     #[test]
     fn add_points() {
         let p1 = Point::new(1, 2);
@@ -0,0 +1,349 @@
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::params::Param;
+
+/// Every run appends its timings to this file, in the current directory,
+/// unless another one is given with `--history`.
+pub const DEFAULT_PATH: &str = "history.csv";
+
+const HEADER: &str = "timestamp,mode,profile,year,day,phase,input_hash,nanos";
+
+/// The profile this crate was built with, as debug builds are much slower.
+pub fn profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+/// Hash of the input and of the parameter overrides, so that timings are
+/// only compared between runs that solved the same puzzle (FNV-1a).
+pub fn input_hash(input: &str, params: &[Param]) -> u64 {
+    let params = params
+        .iter()
+        .map(|p| format!("{}={}", p.name, p.value))
+        .collect::<Vec<_>>()
+        .join(",");
+    [input, "\0", &params]
+        .iter()
+        .flat_map(|s| s.bytes())
+        .fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// `run` for a single run, `bench` for the median of a benchmark.
+    pub mode: String,
+    /// `debug` or `release`, see `profile`.
+    pub profile: String,
+    pub year: u16,
+    pub day: u8,
+    /// `parse`, `a` or `b`.
+    pub phase: String,
+    pub input_hash: u64,
+    pub elapsed: Duration,
+}
+
+impl Record {
+    pub fn new(
+        mode: &str,
+        year: u16,
+        day: u8,
+        phase: &str,
+        input_hash: u64,
+        elapsed: Duration,
+    ) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Record {
+            timestamp,
+            mode: mode.to_owned(),
+            profile: profile().to_owned(),
+            year,
+            day,
+            phase: phase.to_owned(),
+            input_hash,
+            elapsed,
+        }
+    }
+}
+
+impl FromStr for Record {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = s.split(',').collect::<Vec<_>>();
+        let [timestamp, mode, profile, year, day, phase, input_hash, nanos] = fields[..] else {
+            return Err(format!("expected 8 fields, found {}", fields.len()));
+        };
+        let number = |field: &str| {
+            field
+                .parse::<u64>()
+                .map_err(|_| format!("invalid number {:?}", field))
+        };
+        Ok(Record {
+            timestamp: number(timestamp)?,
+            mode: mode.to_owned(),
+            profile: profile.to_owned(),
+            year: year
+                .parse()
+                .map_err(|_| format!("invalid year {:?}", year))?,
+            day: day.parse().map_err(|_| format!("invalid day {:?}", day))?,
+            phase: phase.to_owned(),
+            input_hash: u64::from_str_radix(input_hash, 16)
+                .map_err(|_| format!("invalid hash {:?}", input_hash))?,
+            elapsed: Duration::from_nanos(number(nanos)?),
+        })
+    }
+}
+
+fn format_record(r: &Record) -> String {
+    format!(
+        "{},{},{},{},{},{},{:016x},{}",
+        r.timestamp,
+        r.mode,
+        r.profile,
+        r.year,
+        r.day,
+        r.phase,
+        r.input_hash,
+        r.elapsed.as_nanos()
+    )
+}
+
+/// Appends the records with a single write, so that days solved in
+/// parallel do not interleave their lines.
+pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut content = String::new();
+    if file.metadata()?.len() == 0 {
+        content.push_str(HEADER);
+        content.push('\n');
+    }
+    for record in records {
+        content.push_str(&format_record(record));
+        content.push('\n');
+    }
+    file.write_all(content.as_bytes())
+}
+
+pub fn parse_history(content: &str) -> Result<Vec<Record>, String> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with("timestamp,"))
+        .map(|(i, line)| line.parse().map_err(|e| format!("line {}: {}", i + 1, e)))
+        .collect()
+}
+
+pub fn read(path: &Path) -> io::Result<Vec<Record>> {
+    let content = fs::read_to_string(path)?;
+    parse_history(&content).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
+
+/// What the latest run of a phase is compared with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Baseline {
+    Previous,
+    Best,
+}
+
+impl FromStr for Baseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "previous" => Ok(Baseline::Previous),
+            "best" => Ok(Baseline::Best),
+            _ => Err(format!("unknown baseline {}, expected previous or best", s)),
+        }
+    }
+}
+
+pub struct Comparison {
+    pub mode: String,
+    pub profile: String,
+    pub year: u16,
+    pub day: u8,
+    pub phase: String,
+    pub input_hash: u64,
+    pub baseline: Duration,
+    pub latest: Duration,
+}
+
+impl Comparison {
+    /// Relative change of the latest run, e.g. `0.25` when it is 25% slower.
+    pub fn change(&self) -> f64 {
+        self.latest.as_secs_f64() / self.baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0
+    }
+}
+
+/// Compares the latest record of every phase with the baseline, among the
+/// records of the same mode, build profile, year, day, phase and input. Phases
+/// with a single record are skipped.
+pub fn compare(records: &[Record], baseline: Baseline) -> Vec<Comparison> {
+    let mut groups: HashMap<_, Vec<&Record>> = HashMap::new();
+    for r in records {
+        groups
+            .entry((
+                r.year,
+                r.day,
+                r.phase.as_str(),
+                r.mode.as_str(),
+                r.profile.as_str(),
+                r.input_hash,
+            ))
+            .or_default()
+            .push(r);
+    }
+    let mut comparisons = groups
+        .into_values()
+        .filter_map(|group| {
+            let (latest, earlier) = group.split_last()?;
+            let baseline = match baseline {
+                Baseline::Previous => earlier.last()?.elapsed,
+                Baseline::Best => earlier.iter().map(|r| r.elapsed).min()?,
+            };
+            Some(Comparison {
+                mode: latest.mode.clone(),
+                profile: latest.profile.clone(),
+                year: latest.year,
+                day: latest.day,
+                phase: latest.phase.clone(),
+                input_hash: latest.input_hash,
+                baseline,
+                latest: latest.elapsed,
+            })
+        })
+        .collect::<Vec<_>>();
+    comparisons.sort_by(|a, b| {
+        let key = |c: &Comparison| {
+            let (phase, mode, profile) = (c.phase.clone(), c.mode.clone(), c.profile.clone());
+            (c.year, c.day, phase, mode, profile, c.input_hash)
+        };
+        key(a).cmp(&key(b))
+    });
+    comparisons
+}
+
+/// Prints the comparisons and returns how many are slower than `threshold`
+/// (a ratio, `0.1` for 10%).
+pub fn print_comparisons(comparisons: &[Comparison], threshold: f64) -> usize {
+    println!(
+        "{:>4} | {:>3} | {:<5} | {:<5} | {:<7} | {:<16} | {:>10} | {:>10} | Change",
+        "Year", "Day", "Phase", "Mode", "Profile", "Input", "Baseline", "Latest"
+    );
+    println!(
+        "{}",
+        "-".repeat(4 + 3 + 5 + 5 + 7 + 16 + 10 + 10 + 6 + 8 * 3)
+    );
+    let mut slower = 0;
+    for c in comparisons {
+        let change = c.change();
+        let flag = if change > threshold {
+            slower += 1;
+            " SLOWER"
+        } else {
+            ""
+        };
+        println!(
+            "{:>4} | {:>3} | {:<5} | {:<5} | {:<7} | {:016x} | {:>10} | {:>10} | {:+.1}%{}",
+            c.year,
+            c.day,
+            c.phase,
+            c.mode,
+            c.profile,
+            c.input_hash,
+            format!("{:.2?}", c.baseline),
+            format!("{:.2?}", c.latest),
+            change * 100.0,
+            flag
+        );
+    }
+    slower
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{compare, input_hash, parse_history, Baseline, Record};
+
+    fn record(phase: &str, millis: u64) -> Record {
+        Record {
+            timestamp: 0,
+            mode: "run".to_owned(),
+            profile: "release".to_owned(),
+            year: 2022,
+            day: 16,
+            phase: phase.to_owned(),
+            input_hash: 0xab,
+            elapsed: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn parse_records() {
+        let records = parse_history(
+            "timestamp,mode,profile,year,day,phase,input_hash,nanos\n\
+             1670000000,run,debug,2022,16,b,00000000000000ab,2500000\n",
+        )
+        .unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].profile, "debug");
+        assert_eq!(records[0].input_hash, 0xab);
+        assert_eq!(records[0].elapsed, Duration::from_micros(2500));
+        assert!(parse_history("1,run,16\n").is_err());
+    }
+
+    #[test]
+    fn hash_depends_on_params() {
+        let params = ["row=10".parse().unwrap()];
+        assert_eq!(input_hash("x", &[]), input_hash("x", &[]));
+        assert_ne!(input_hash("x", &[]), input_hash("x", &params));
+        assert_ne!(input_hash("x", &[]), input_hash("y", &[]));
+    }
+
+    #[test]
+    fn compare_with_baseline() {
+        let records = [
+            record("a", 10),
+            record("b", 100),
+            record("b", 80),
+            record("b", 120),
+        ];
+        let previous = compare(&records, Baseline::Previous);
+        assert_eq!(previous.len(), 1);
+        assert_eq!(previous[0].baseline, Duration::from_millis(80));
+        assert!((previous[0].change() - 0.5).abs() < 1e-9);
+        let best = compare(&records, Baseline::Best);
+        assert_eq!(best[0].baseline, Duration::from_millis(80));
+        assert_eq!(best[0].latest, Duration::from_millis(120));
+        let mut debug = record("b", 1000);
+        debug.profile = "debug".to_owned();
+        let mixed = compare(
+            &[record("b", 100), debug.clone(), debug],
+            Baseline::Previous,
+        );
+        assert_eq!(mixed.len(), 1);
+        assert_eq!(mixed[0].change(), 0.0);
+    }
+}
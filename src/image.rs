@@ -0,0 +1,245 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+
+use crate::grid::{Grid, Point};
+
+/// An RGB color.
+pub type Color = [u8; 3];
+
+pub const BLACK: Color = [0, 0, 0];
+pub const WHITE: Color = [255, 255, 255];
+
+/// How a grid is rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageOptions {
+    /// Width and height of a cell, in pixels.
+    pub scale: u32,
+    /// Color of the empty cells.
+    pub background: Color,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        ImageOptions {
+            scale: 4,
+            background: BLACK,
+        }
+    }
+}
+
+/// An RGB image, stored row by row from the top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pixels: Vec<Color>,
+}
+
+impl Image {
+    pub fn new(width: u32, height: u32, background: Color) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width as usize * height as usize],
+        }
+    }
+
+    pub fn get(&self, x: u32, y: u32) -> Color {
+        self.pixels[(y * self.width + x) as usize]
+    }
+
+    pub fn set(&mut self, x: u32, y: u32, color: Color) {
+        self.pixels[(y * self.width + x) as usize] = color;
+    }
+
+    /// The image as a binary PPM, which most image viewers open.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.pixels.iter().flatten());
+        ppm
+    }
+
+    /// The image as a PNG. Its pixels are stored uncompressed, so the file
+    /// is about as large as a PPM.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity((self.width as usize * 3 + 1) * self.height as usize);
+        for row in self.pixels.chunks(self.width.max(1) as usize) {
+            // Each row starts with its filter type, none.
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+        let mut header = Vec::new();
+        header.extend(self.width.to_be_bytes());
+        header.extend(self.height.to_be_bytes());
+        // 8 bits per channel, RGB, default compression, filters and no
+        // interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut png, b"IHDR", &header);
+        png_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// Writes the image to `path`, as a PNG if its extension is `png`, as a
+    /// PPM otherwise.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        let content = match path.extension() {
+            Some(extension) if extension.eq_ignore_ascii_case("png") => self.to_png(),
+            _ => self.to_ppm(),
+        };
+        fs::File::create(path)?.write_all(&content)
+    }
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+/// A zlib stream holding `data` in stored (uncompressed) deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        stream.push(blocks.peek().is_none() as u8);
+        let len = block.len() as u16;
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| {
+            (crc >> 1) ^ (0xedb88320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1, 0), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+/// A palette for character grids, mapping the given characters to their
+/// colors and the others to white.
+pub fn char_palette(colors: &[(char, Color)]) -> impl Fn(&char) -> Color + '_ {
+    |c| {
+        colors
+            .iter()
+            .find(|(other, _)| other == c)
+            .map_or(WHITE, |(_, color)| *color)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Renders the grid, coloring each cell holding a value with `palette`.
+    /// The image covers the bounds of the grid and every value outside them,
+    /// which sparse grids can hold, upside down unless `going_down`.
+    pub fn to_image<F: Fn(&T) -> Color>(&self, options: &ImageOptions, palette: F) -> Image {
+        let mut corners = self.iter().map(|(p, _)| p).collect::<Vec<_>>();
+        if self.width > 0 && self.height > 0 {
+            corners.push(Point::new(0, 0));
+            corners.push(Point::new(self.width as i64 - 1, self.height as i64 - 1));
+        }
+        let (Some(min_x), Some(max_x)) = (
+            corners.iter().map(|p| p.x).min(),
+            corners.iter().map(|p| p.x).max(),
+        ) else {
+            return Image::new(0, 0, options.background);
+        };
+        let min_y = corners.iter().map(|p| p.y).min().unwrap();
+        let max_y = corners.iter().map(|p| p.y).max().unwrap();
+        let scale = options.scale;
+        let width = (max_x - min_x + 1) as u32;
+        let height = (max_y - min_y + 1) as u32;
+        let mut image = Image::new(width * scale, height * scale, options.background);
+        for (point, value) in self.iter() {
+            let column = (point.x - min_x) as u32;
+            let row = if self.going_down {
+                point.y - min_y
+            } else {
+                max_y - point.y
+            } as u32;
+            let color = palette(value);
+            for y in row * scale..(row + 1) * scale {
+                for x in column * scale..(column + 1) * scale {
+                    image.set(x, y, color);
+                }
+            }
+        }
+        image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{adler32, char_palette, crc32, Image, ImageOptions, BLACK, WHITE};
+    use crate::grid::{Grid, Point};
+
+    const RED: [u8; 3] = [255, 0, 0];
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn render_grid() {
+        let grid = Grid::from(&[String::from("#."), String::from(".#")]).unwrap();
+        let options = ImageOptions {
+            scale: 2,
+            background: BLACK,
+        };
+        let image = grid.to_image(&options, char_palette(&[('#', RED), ('.', BLACK)]));
+        assert_eq!((image.width, image.height), (4, 4));
+        assert_eq!(image.get(1, 1), RED);
+        assert_eq!(image.get(2, 1), BLACK);
+        assert_eq!(image.get(3, 3), RED);
+    }
+
+    #[test]
+    fn render_sparse_grid_upwards() {
+        let mut grid = Grid::new(0, 0, false);
+        grid.insert(Point::new(-1, 0), 'a');
+        grid.insert(Point::new(1, 2), 'b');
+        let image = grid.to_image(&ImageOptions::default(), char_palette(&[('a', RED)]));
+        assert_eq!((image.width, image.height), (12, 12));
+        assert_eq!(image.get(0, 11), RED);
+        assert_eq!(image.get(11, 0), WHITE);
+        assert_eq!(image.get(11, 11), BLACK);
+        let empty = Grid::<char>::new(0, 0, true);
+        assert_eq!(empty.to_image(&ImageOptions::default(), |_| WHITE).width, 0);
+    }
+
+    #[test]
+    fn encode_images() {
+        let mut image = Image::new(2, 1, WHITE);
+        image.set(1, 0, RED);
+        assert_eq!(image.to_ppm(), b"P6\n2 1\n255\n\xff\xff\xff\xff\x00\x00");
+        let png = image.to_png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR"));
+        assert!(png.ends_with(b"\x00\x00\x00\x00IEND\xae\x42\x60\x82"));
+        let raw = b"\x00\xff\xff\xff\xff\x00\x00";
+        assert!(png.windows(raw.len()).any(|w| w == raw));
+    }
+}
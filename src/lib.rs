@@ -0,0 +1,250 @@
+#![allow(clippy::new_ret_no_self)]
+
+use std::fmt;
+use std::path::Path;
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub mod answers;
+pub mod bench;
+pub mod grid;
+pub mod history;
+pub mod image;
+pub mod memory;
+pub mod output;
+pub mod params;
+pub mod pathfinding;
+pub mod progress;
+pub mod registry;
+pub mod runner;
+pub mod utils;
+pub mod watch;
+
+pub mod year2022;
+
+use memory::MemoryStats;
+use params::{Param, ParamError, Params};
+use progress::Progress;
+use utils::{Answer, Day, ParseError, Solution};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    UnknownYear(u16),
+    UnknownDay(u16, u8),
+    UnknownPart(char),
+    Parse(ParseError),
+    Param(ParamError),
+    /// The input could not be read.
+    Io(String),
+    /// A part did not finish, e.g. it timed out.
+    Failed(String),
+}
+
+impl Error {
+    pub fn in_file<P: AsRef<Path>>(self, file: P) -> Self {
+        match self {
+            Error::Parse(e) => Error::Parse(e.in_file(file)),
+            e => e,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+impl From<ParamError> for Error {
+    fn from(e: ParamError) -> Self {
+        Error::Param(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UnknownYear(year) => write!(f, "year {} is not implemented", year),
+            Error::UnknownDay(year, day) => write!(f, "day {} of {} is not implemented", day, year),
+            Error::UnknownPart(part) => write!(f, "unknown part {}, expected a or b", part),
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Param(e) => write!(f, "{}", e),
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Failed(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The solutions of a day, along with the time spent parsing its input.
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub parse_elapsed: Duration,
+    /// Only measured with the `alloc-stats` feature.
+    pub parse_memory: Option<MemoryStats>,
+    pub solutions: Vec<Solution>,
+}
+
+/// How `run` solves the parts of a day.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunOptions {
+    /// Parts that take longer are reported as failed.
+    pub timeout: Option<Duration>,
+    /// Days with long loops report their progress on stderr.
+    pub verbose: bool,
+}
+
+/// Solves the part, reporting a panic as a failed part. With a `timeout`,
+/// the part runs on a worker thread and is given up after that long. The
+/// thread cannot be stopped, so a part that timed out keeps running in the
+/// background until it finishes or the process exits, and the memory of the
+/// parts after it is no longer reported.
+fn solve_part(
+    day: &Arc<dyn Day>,
+    part: char,
+    timeout: Option<Duration>,
+    progress: Progress,
+) -> Solution {
+    let before = Instant::now();
+    let solution = match timeout {
+        None => runner::run_isolated(|| Ok(day.solution(part, &progress))),
+        Some(timeout) => {
+            let (sender, receiver) = mpsc::channel();
+            let day = Arc::clone(day);
+            thread::spawn(move || {
+                // The receiver is gone if the part timed out.
+                let _ = sender.send(runner::run_isolated(|| Ok(day.solution(part, &progress))));
+            });
+            receiver.recv_timeout(timeout).unwrap_or_else(|_| {
+                // The abandoned thread still allocates.
+                memory::set_reporting(false);
+                Err(format!("timed out after {:.2?}", timeout))
+            })
+        }
+    };
+    solution.unwrap_or_else(|e| Solution {
+        part,
+        answer: Err(e),
+        elapsed: before.elapsed(),
+        memory: None,
+    })
+}
+
+fn solve_parts(
+    day: Box<dyn Day>,
+    parts: &[char],
+    timeout: Option<Duration>,
+    progress: &dyn Fn(char) -> Progress,
+    on_solved: &mut dyn FnMut(&Solution),
+) -> Vec<Solution> {
+    let day = Arc::from(day);
+    parts
+        .iter()
+        .map(|part| {
+            let solution = solve_part(&day, *part, timeout, progress(*part));
+            on_solved(&solution);
+            solution
+        })
+        .collect()
+}
+
+fn check_parts(parts: &[char]) -> Result<(), Error> {
+    match parts.iter().find(|p| !['a', 'b'].contains(p)) {
+        Some(part) => Err(Error::UnknownPart(*part)),
+        None => Ok(()),
+    }
+}
+
+/// Looks up a day, telling apart unknown years from unknown days.
+fn find_day(year: u16, day: u8) -> Result<&'static registry::DayInfo, Error> {
+    registry::find_year(year).ok_or(Error::UnknownYear(year))?;
+    registry::find(year, day).ok_or(Error::UnknownDay(year, day))
+}
+
+/// Parses `input` for the given day and solves the requested parts,
+/// calling `on_solved` as soon as each part is solved.
+pub fn run(
+    year: u16,
+    day: u8,
+    input: &str,
+    params: &[Param],
+    parts: &[char],
+    options: RunOptions,
+    on_solved: &mut dyn FnMut(&Solution),
+) -> Result<DayRun, Error> {
+    check_parts(parts)?;
+    let info = find_day(year, day)?;
+    let params = Params::resolve(info.params, params)?;
+    let before = Instant::now();
+    let (solver, parse_memory) = memory::measure(|| (info.build)(input, &params));
+    let parse_elapsed = before.elapsed();
+    let progress = |part: char| match options.verbose {
+        true => Progress::new(format!("{} day {} part {}", year, day, part)),
+        false => Progress::disabled(),
+    };
+    Ok(DayRun {
+        parse_elapsed,
+        parse_memory,
+        solutions: solve_parts(solver?, parts, options.timeout, &progress, on_solved),
+    })
+}
+
+/// Solves one part (`'a'` or `'b'`) of a day of a year for the given
+/// input, using the constants of the real puzzle.
+pub fn solve(year: u16, day: u8, part: char, input: &str) -> Result<Answer, Error> {
+    let options = RunOptions::default();
+    let mut run = run(year, day, input, &[], &[part], options, &mut |_| {})?;
+    run.solutions.remove(0).answer.map_err(Error::Failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{solve, solve_parts, Answer, Day, Error};
+    use crate::progress::Progress;
+
+    /// A day whose part B panics.
+    struct Broken;
+
+    impl Day for Broken {
+        fn solve_a(&self, _progress: &Progress) -> Answer {
+            Answer::Integer(1)
+        }
+
+        fn solve_b(&self, _progress: &Progress) -> Answer {
+            panic!("no cube");
+        }
+    }
+
+    #[test]
+    fn solve_from_str() {
+        let input = "1000\n2000\n\n4000\n\n5000\n6000\n";
+        assert_eq!(solve(2022, 1, 'a', input), Ok(Answer::Integer(11000)));
+        assert_eq!(solve(2022, 1, 'b', input), Ok(Answer::Integer(18000)));
+        assert_eq!(
+            solve(2022, 26, 'a', input),
+            Err(Error::UnknownDay(2022, 26))
+        );
+        assert_eq!(solve(2015, 1, 'a', input), Err(Error::UnknownYear(2015)));
+        assert_eq!(solve(2022, 1, 'c', input), Err(Error::UnknownPart('c')));
+        assert!(matches!(solve(2022, 1, 'a', "x"), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn panic_fails_only_its_part() {
+        for timeout in [None, Some(Duration::from_secs(10))] {
+            let solutions = solve_parts(
+                Box::new(Broken),
+                &['a', 'b'],
+                timeout,
+                &|_| Progress::disabled(),
+                &mut |_| {},
+            );
+            assert_eq!(solutions[0].answer, Ok(Answer::Integer(1)));
+            assert_eq!(solutions[1].answer, Err("panicked: no cube".to_owned()));
+        }
+    }
+}
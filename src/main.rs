@@ -1,81 +1,472 @@
-#![allow(clippy::new_ret_no_self)]
-
-use std::path::Path;
-
-use clap::Parser;
-
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-mod grid;
-mod utils;
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process,
+    time::Duration,
+};
+
+use clap::{Parser, Subcommand};
+
+use advent_2022::answers::{self, Check};
+use advent_2022::bench;
+use advent_2022::history::{self, Baseline, Record};
+use advent_2022::memory;
+use advent_2022::output::{self, Format};
+use advent_2022::params::{self, Param};
+use advent_2022::registry;
+use advent_2022::runner::{self, DayReport, DaySelection};
+use advent_2022::utils::{Input, Solution};
+use advent_2022::watch::Watcher;
+use advent_2022::{DayRun, RunOptions};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
-    day: u8,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Day(s) to run: a day number, `all`, a range such as `1..=10` or a list such as `3,7,12`
+    #[arg(required = true)]
+    days: Option<DaySelection>,
+
+    /// Year of the puzzles, the latest one by default
+    #[arg(short, long, default_value_t = registry::latest_year())]
+    year: u16,
 
     #[arg(short, long)]
     test: bool,
 
+    /// Use the named example input `dayN-test-<NAME>.txt`, with its own parameters and answers
+    #[arg(short, long, value_name = "NAME", conflicts_with = "input")]
+    example: Option<String>,
+
     #[arg(long, default_value = "data")]
     data_dir: String,
+
+    /// Read the input from this file, or from stdin with `-`, instead of the data directory
+    #[arg(short, long)]
+    input: Option<Input>,
+
+    /// Override a puzzle parameter, e.g. `--param rounds=30` (see `list`)
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    params: Vec<Param>,
+
+    /// Only solve this part (`a` or `b`)
+    #[arg(short, long, value_parser = runner::parse_part)]
+    part: Option<char>,
+
+    /// Give up on a part after this long, e.g. `30s`, `500ms` or `2m`
+    #[arg(long, value_parser = runner::parse_duration, conflicts_with = "bench")]
+    timeout: Option<Duration>,
+
+    /// Report the progress of long-running parts on stderr
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Compare the answers with the expected ones stored in `dayN[-test].answers`
+    #[arg(long)]
+    check: bool,
+
+    /// Parse and solve each day N times and report timing statistics
+    #[arg(long, value_name = "N", conflicts_with = "check")]
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// Output format of the answers and timings: text, json or csv
+    #[arg(long, default_value = "text", conflicts_with_all = ["check", "bench"])]
+    format: Format,
+
+    /// Append the timings of each run to this file, see `compare`
+    #[arg(long, value_name = "FILE", default_value = history::DEFAULT_PATH)]
+    history: PathBuf,
+
+    /// Do not record the timings of this run
+    #[arg(long, conflicts_with = "history")]
+    no_history: bool,
+
+    /// Solve up to N days at the same time, printing them in order
+    #[arg(
+        short,
+        long,
+        value_name = "N",
+        default_value_t = 1,
+        conflicts_with = "bench"
+    )]
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: u32,
+
+    /// Run the day again whenever its input changes, until interrupted
+    #[arg(short, long, conflicts_with_all = ["bench", "format"])]
+    watch: bool,
+
+    /// Also run the day again when a file of this directory changes
+    #[arg(long, value_name = "DIR", requires = "watch")]
+    watch_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the available days and what their parts compute
+    List,
+    /// Compare the latest recorded timings with earlier runs and flag the slower ones
+    Compare {
+        #[arg(long, value_name = "FILE", default_value = history::DEFAULT_PATH)]
+        history: PathBuf,
+
+        /// Flag the timings that are more than this percentage slower
+        #[arg(long, value_name = "PERCENT", default_value = "10")]
+        threshold: f64,
+
+        /// Compare with the previous run or with the best one: previous or best
+        #[arg(long, default_value = "previous")]
+        against: Baseline,
+    },
+}
+
+/// Checks the solutions against the expected answers and prints the
+/// differences, returning whether all the known answers match. A day
+/// without an answers file fails, as nothing could be checked.
+fn check_day(day: u8, input: &Input, run: &Result<DayRun, String>) -> bool {
+    let expected = match input {
+        Input::File(filepath) => answers::read_answers(filepath),
+        Input::Stdin => Err(io::Error::other("reading from stdin")),
+    };
+    let expected = match expected {
+        Ok(expected) => expected,
+        Err(e) => {
+            println!("Day {}: FAILED, no expected answers ({})", day, e);
+            return false;
+        }
+    };
+    let solutions = match run {
+        Ok(run) => &run.solutions,
+        Err(e) => {
+            println!("Day {}: FAILED, {}", day, e);
+            return false;
+        }
+    };
+    let mut success = true;
+    for solution in solutions {
+        match answers::check(solution, &expected) {
+            Check::Ok => println!("Day {} part {}: ok", day, solution.part),
+            Check::Missing => println!("Day {} part {}: no expected answer", day, solution.part),
+            Check::Failed(e) => {
+                success = false;
+                println!("Day {} part {}: FAILED, {}", day, solution.part, e);
+            }
+            Check::Mismatch { expected, actual } => {
+                success = false;
+                println!("Day {} part {}: FAILED", day, solution.part);
+                println!("{}", answers::diff(&expected, &actual));
+            }
+        }
+    }
+    success
+}
+
+/// Reads the input and its parameters: the ones stored next to the input
+/// file, then the ones given on the command line.
+fn read_input(input: &Input, overrides: &[Param]) -> Result<(String, Vec<Param>), String> {
+    let content = input
+        .read()
+        .map_err(|e| format!("{}: {}", input.name().display(), e))?;
+    let mut params = match input {
+        Input::File(path) => params::read_params(path).map_err(|e| e.to_string())?,
+        Input::Stdin => vec![],
+    };
+    params.extend_from_slice(overrides);
+    Ok((content, params))
+}
+
+/// Appends the records to the history, only warning on failure since
+/// the answers matter more than their timings.
+fn record_history(path: Option<&Path>, records: &[Record]) {
+    if let Some(path) = path {
+        if let Err(e) = history::append(path, records) {
+            eprintln!("warning: {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Benchmarks the day, printing the statistics of each phase and recording
+/// their medians, and returns whether it ran successfully.
+fn bench_day(
+    year: u16,
+    day: u8,
+    input: &Input,
+    overrides: &[Param],
+    parts: &[char],
+    runs: u32,
+    history: Option<&Path>,
+) -> bool {
+    println!("Day {} ({} runs)", day, runs);
+    let mut input_hash = 0;
+    let phases = runner::run_isolated(|| {
+        let (content, params) = read_input(input, overrides)?;
+        input_hash = history::input_hash(&content, &params);
+        bench::bench(year, day, &content, &params, parts, runs as usize)
+            .map_err(|e| e.in_file(input.name()).to_string())
+    });
+    match phases {
+        Ok(phases) => {
+            bench::print_phases(&phases);
+            let records = phases
+                .iter()
+                .map(|p| Record::new("bench", year, day, &p.name, input_hash, p.stats.median))
+                .collect::<Vec<_>>();
+            record_history(history, &records);
+            true
+        }
+        Err(e) => {
+            eprintln!("error: day {}: {}", day, e);
+            false
+        }
+    }
+}
+
+/// Parses and solves the day, printing the answers as they are solved with
+/// `output`, and records the timings in the history.
+fn run_day(
+    args: &Cli,
+    day: u8,
+    input: &Input,
+    parts: &[char],
+    history: Option<&Path>,
+    output: bool,
+) -> DayReport {
+    let mut input_hash = 0;
+    let run = runner::run_isolated(|| {
+        let (content, params) = read_input(input, &args.params)?;
+        input_hash = history::input_hash(&content, &params);
+        let mut on_solved = |solution: &Solution| {
+            if output {
+                runner::print_solution(solution);
+            }
+        };
+        let options = RunOptions {
+            timeout: args.timeout,
+            verbose: args.verbose,
+        };
+        advent_2022::run(
+            args.year,
+            day,
+            &content,
+            &params,
+            parts,
+            options,
+            &mut on_solved,
+        )
+        .map_err(|e| e.in_file(input.name()).to_string())
+    });
+    if let Ok(run) = &run {
+        let mut records = vec![Record::new(
+            "run",
+            args.year,
+            day,
+            "parse",
+            input_hash,
+            run.parse_elapsed,
+        )];
+        for solution in run.solutions.iter().filter(|s| s.answer.is_ok()) {
+            let part = solution.part.to_string();
+            records.push(Record::new(
+                "run",
+                args.year,
+                day,
+                &part,
+                input_hash,
+                solution.elapsed,
+            ));
+        }
+        record_history(history, &records);
+    }
+    DayReport {
+        year: args.year,
+        day,
+        input: input.name(),
+        run,
+    }
+}
+
+/// Prints what follows the answers: the cost of parsing with `output`,
+/// or why the day failed.
+fn finish_run(report: &DayReport, output: bool) {
+    match &report.run {
+        Ok(run) if output => runner::print_parse(run),
+        Ok(_) => {}
+        Err(e) => eprintln!("error: day {}: {}", report.day, e),
+    }
+}
+
+/// Runs the day again whenever its input, the parameters and answers next
+/// to it, or a file of the `--watch-dir` directory change.
+fn watch_day(args: &Cli, day: u8, input: &Path, parts: &[char], history: Option<&Path>) -> ! {
+    let files = vec![
+        input.to_owned(),
+        params::params_path(input),
+        answers::answers_path(input),
+    ];
+    let mut watcher = Watcher::new(files, args.watch_dir.iter().cloned().collect());
+    let input = Input::File(input.to_owned());
+    loop {
+        // Clears the screen and moves the cursor to the top left.
+        print!("\x1b[2J\x1b[H");
+        println!(
+            "Day {} ({}), watching for changes",
+            day,
+            input.name().display()
+        );
+        let report = run_day(args, day, &input, parts, history, !args.check);
+        finish_run(&report, !args.check);
+        if args.check {
+            check_day(day, &input, &report.run);
+        }
+        let _ = io::stdout().flush();
+        watcher.wait();
+    }
 }
 
 fn main() {
-    let args = Cli::parse();
-
-    let suffix = if args.test { "-test" } else { "" };
-    let filename = format!("day{}{}.txt", &args.day, suffix);
-    let filepath = Path::new(&args.data_dir).join(&filename);
-
-    match &args.day {
-        1 => day1::Day1::new(&filepath).output_solutions(),
-        2 => day2::Day2::new(&filepath).output_solutions(),
-        3 => day3::Day3::new(&filepath).output_solutions(),
-        4 => day4::Day4::new(&filepath).output_solutions(),
-        5 => day5::Day5::new(&filepath).output_solutions(),
-        6 => day6::Day6::new(&filepath).output_solutions(),
-        7 => day7::Day7::new(&filepath).output_solutions(),
-        8 => day8::Day8::new(&filepath).output_solutions(),
-        9 => day9::Day9::new(&filepath).output_solutions(),
-        10 => day10::Day10::new(&filepath).output_solutions(),
-        11 => day11::Day11::new(&filepath).output_solutions(),
-        12 => day12::Day12::new(&filepath).output_solutions(),
-        13 => day13::Day13::new(&filepath).output_solutions(),
-        14 => day14::Day14::new(&filepath).output_solutions(),
-        15 => day15::Day15::new(&filepath, args.test).output_solutions(),
-        16 => day16::Day16::new(&filepath).output_solutions(),
-        17 => day17::Day17::new(&filepath).output_solutions(),
-        18 => day18::Day18::new(&filepath).output_solutions(),
-        19 => day19::Day19::new(&filepath).output_solutions(),
-        20 => day20::Day20::new(&filepath).output_solutions(),
-        21 => day21::Day21::new(&filepath).output_solutions(),
-        22 => day22::Day22::new(&filepath).output_solutions(),
-        23 => day23::Day23::new(&filepath).output_solutions(),
-        24 => day24::Day24::new(&filepath).output_solutions(),
-        25 => day25::Day25::new(&filepath).output_solutions(),
-        _ => panic!("Day {} not implemented", args.day),
+    let mut args = Cli::parse();
+    match args.command {
+        Some(Command::List) => {
+            for (i, year) in registry::YEARS.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                println!("Year {}", year.year);
+                runner::print_days(year.days);
+            }
+            return;
+        }
+        Some(Command::Compare {
+            history,
+            threshold,
+            against,
+        }) => {
+            let records = history::read(&history).unwrap_or_else(|e| {
+                eprintln!("error: {}: {}", history.display(), e);
+                process::exit(2);
+            });
+            let comparisons = history::compare(&records, against);
+            let slower = history::print_comparisons(&comparisons, threshold / 100.0);
+            println!("\n{} timing(s) more than {}% slower", slower, threshold);
+            if slower > 0 {
+                process::exit(1);
+            }
+            return;
+        }
+        None => {}
+    }
+    let Some(year) = registry::find_year(args.year) else {
+        eprintln!("error: year {} is not implemented", args.year);
+        process::exit(2);
+    };
+    let selection = args
+        .days
+        .take()
+        .expect("days are required without a subcommand");
+    let days = selection.days(year).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(2);
+    });
+    let suffix = match &args.example {
+        Some(name) => format!("-test-{}", name),
+        None if args.test => "-test".to_owned(),
+        None => String::new(),
+    };
+    let single_day = days.len() == 1;
+    if args.input.is_some() && !single_day {
+        eprintln!("error: --input can only be used with a single day");
+        process::exit(2);
+    }
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec!['a', 'b'],
     };
+    let history = (!args.no_history).then_some(args.history.as_path());
+
+    let input_of = |day: u8| {
+        args.input.clone().unwrap_or_else(|| {
+            let dir = Path::new(&args.data_dir).join(args.year.to_string());
+            Input::File(dir.join(format!("day{}{}.txt", day, suffix)))
+        })
+    };
+
+    // Days solved at the same time would mix their allocations.
+    memory::set_reporting(cfg!(feature = "alloc-stats") && args.jobs == 1);
+
+    if args.watch {
+        match input_of(days[0]) {
+            _ if !single_day => eprintln!("error: --watch can only be used with a single day"),
+            Input::Stdin => eprintln!("error: --watch cannot read from stdin"),
+            Input::File(path) => watch_day(&args, days[0], &path, &parts, history),
+        }
+        process::exit(2);
+    }
+
+    let mut reports = vec![];
+    let mut success = true;
+    if let Some(runs) = args.bench {
+        for day in days {
+            let input = input_of(day);
+            success &= bench_day(args.year, day, &input, &args.params, &parts, runs, history);
+            if !single_day {
+                println!();
+            }
+        }
+    } else {
+        let output = !args.check && args.format == Format::Text;
+        let mut finish = |report: DayReport| {
+            finish_run(&report, output);
+            if args.check {
+                success &= check_day(report.day, &input_of(report.day), &report.run);
+            } else {
+                success &= !report.failed();
+                if output && !single_day {
+                    println!();
+                }
+            }
+            reports.push(report);
+        };
+        if args.jobs == 1 {
+            for day in days {
+                if output && !single_day {
+                    println!("Day {}", day);
+                }
+                finish(run_day(&args, day, &input_of(day), &parts, history, output));
+            }
+        } else {
+            // The answers are printed once every previous day is done.
+            let run = |&day: &u8| run_day(&args, day, &input_of(day), &parts, history, false);
+            runner::run_parallel(&days, args.jobs as usize, run, |report| {
+                if output {
+                    if !single_day {
+                        println!("Day {}", report.day);
+                    }
+                    if let Ok(run) = &report.run {
+                        run.solutions.iter().for_each(runner::print_solution);
+                    }
+                }
+                finish(report);
+            });
+        }
+    }
+
+    match args.format {
+        Format::Json => println!("{}", output::to_json(&reports)),
+        Format::Csv => println!("{}", output::to_csv(&reports)),
+        Format::Text if !single_day && !args.check && args.bench.is_none() => {
+            runner::print_summary(&reports)
+        }
+        Format::Text => {}
+    }
+    if !success {
+        process::exit(1);
+    }
 }
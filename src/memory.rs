@@ -0,0 +1,135 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+static REPORTING: AtomicBool = AtomicBool::new(false);
+
+/// Wraps the system allocator to count allocations and track the peak of
+/// live memory. The counters are global, so the statistics of parts that
+/// run concurrently are mixed. Only a binary should install it, as its
+/// `#[global_allocator]`.
+pub struct CountingAllocator;
+
+/// Makes `measure` report statistics, or stop reporting them. Turn it on
+/// only with `CountingAllocator` installed and while a single part runs at
+/// a time.
+pub fn set_reporting(reporting: bool) {
+    REPORTING.store(reporting, Ordering::Relaxed);
+}
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            LIVE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+            record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryStats {
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    /// Highest live memory reached, above what was live at the start.
+    pub peak_bytes: u64,
+}
+
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..=1023 => format!("{}B", bytes),
+        1024..=1_048_575 => format!("{:.2}KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.2}MiB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+impl fmt::Display for MemoryStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.allocated_bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Runs `f` and returns what it allocated, or `None` when reporting is off.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<MemoryStats>) {
+    if !REPORTING.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let result = f();
+    let stats = MemoryStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_bytes, measure, MemoryStats};
+
+    #[test]
+    fn format_stats() {
+        let stats = MemoryStats {
+            allocations: 3,
+            allocated_bytes: 2048,
+            peak_bytes: 3 * 1_048_576,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "3 allocs, 2.00KiB allocated, 3.00MiB peak"
+        );
+        assert_eq!(format_bytes(12), "12B");
+    }
+
+    #[test]
+    fn measure_without_reporting() {
+        // The tests run concurrently, without the counting allocator.
+        let (v, stats) = measure(|| vec![0u8; 4096]);
+        assert_eq!(v.len(), 4096);
+        assert_eq!(stats, None);
+    }
+}
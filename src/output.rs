@@ -0,0 +1,297 @@
+use std::str::FromStr;
+
+use crate::memory::MemoryStats;
+use crate::runner::DayReport;
+use crate::utils::Answer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format {}, expected text, json or csv", s)),
+        }
+    }
+}
+
+/// One row of the structured output: a part, or a day that failed before
+/// solving any part (in which case only `year`, `day`, `input` and `error`
+/// are set). A part without a puzzle has no `answer` either.
+struct Record {
+    year: u16,
+    day: u8,
+    part: Option<char>,
+    input: String,
+    answer: Option<Answer>,
+    parse_ns: Option<u128>,
+    solve_ns: Option<u128>,
+    parse_memory: Option<MemoryStats>,
+    solve_memory: Option<MemoryStats>,
+    error: Option<String>,
+}
+
+fn records(reports: &[DayReport]) -> Vec<Record> {
+    let mut records = vec![];
+    for report in reports {
+        let input = report.input.display().to_string();
+        match &report.run {
+            Ok(run) => records.extend(run.solutions.iter().map(|s| Record {
+                year: report.year,
+                day: report.day,
+                part: Some(s.part),
+                input: input.clone(),
+                answer: s.answer.as_ref().ok().cloned(),
+                parse_ns: Some(run.parse_elapsed.as_nanos()),
+                solve_ns: Some(s.elapsed.as_nanos()),
+                parse_memory: run.parse_memory,
+                solve_memory: s.memory,
+                error: s.answer.as_ref().err().cloned(),
+            })),
+            Err(e) => records.push(Record {
+                year: report.year,
+                day: report.day,
+                part: None,
+                input,
+                answer: None,
+                parse_ns: None,
+                solve_ns: None,
+                parse_memory: None,
+                solve_memory: None,
+                error: Some(e.clone()),
+            }),
+        }
+    }
+    records
+}
+
+fn json_string(s: &str) -> String {
+    let mut result = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn json_or_null<T, F: Fn(&T) -> String>(value: &Option<T>, f: F) -> String {
+    value.as_ref().map_or("null".to_owned(), f)
+}
+
+/// Integers are written as numbers, other answers as strings.
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Integer(n) => n.to_string(),
+        Answer::NotApplicable => "null".to_owned(),
+        answer => json_string(&answer.to_string()),
+    }
+}
+
+fn json_memory(memory: &MemoryStats) -> String {
+    format!(
+        "{{\"allocations\": {}, \"allocated_bytes\": {}, \"peak_bytes\": {}}}",
+        memory.allocations, memory.allocated_bytes, memory.peak_bytes
+    )
+}
+
+/// Formats the reports as a JSON array with one object per part. The memory
+/// statistics are `null` unless built with the `alloc-stats` feature.
+pub fn to_json(reports: &[DayReport]) -> String {
+    let objects = records(reports)
+        .iter()
+        .map(|r| {
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"input\": {}, \"answer\": {}, \
+                 \"parse_ns\": {}, \"solve_ns\": {}, \"parse_memory\": {}, \
+                 \"solve_memory\": {}, \"error\": {}}}",
+                r.year,
+                r.day,
+                json_or_null(&r.part, |p| json_string(&p.to_string())),
+                json_string(&r.input),
+                json_or_null(&r.answer, json_answer),
+                json_or_null(&r.parse_ns, u128::to_string),
+                json_or_null(&r.solve_ns, u128::to_string),
+                json_or_null(&r.parse_memory, json_memory),
+                json_or_null(&r.solve_memory, json_memory),
+                json_or_null(&r.error, |e| json_string(e)),
+            )
+        })
+        .collect::<Vec<_>>();
+    if objects.is_empty() {
+        "[]".to_owned()
+    } else {
+        format!("[\n{}\n]", objects.join(",\n"))
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+/// Formats the reports as CSV with a header line and one row per part.
+pub fn to_csv(reports: &[DayReport]) -> String {
+    let mut lines = vec![[
+        "year,day,part,input,answer,parse_ns,solve_ns",
+        "parse_allocations,parse_allocated_bytes,parse_peak_bytes",
+        "solve_allocations,solve_allocated_bytes,solve_peak_bytes",
+        "error",
+    ]
+    .join(",")];
+    let memory_fields = |memory: Option<MemoryStats>| match memory {
+        Some(m) => [m.allocations, m.allocated_bytes, m.peak_bytes].map(|n| n.to_string()),
+        None => Default::default(),
+    };
+    for r in records(reports) {
+        let [parse_allocations, parse_allocated, parse_peak] = memory_fields(r.parse_memory);
+        let [solve_allocations, solve_allocated, solve_peak] = memory_fields(r.solve_memory);
+        let fields = [
+            r.year.to_string(),
+            r.day.to_string(),
+            r.part.map_or(String::new(), String::from),
+            r.input,
+            match r.answer {
+                None | Some(Answer::NotApplicable) => String::new(),
+                Some(answer) => answer.to_string(),
+            },
+            r.parse_ns.map_or(String::new(), |n| n.to_string()),
+            r.solve_ns.map_or(String::new(), |n| n.to_string()),
+            parse_allocations,
+            parse_allocated,
+            parse_peak,
+            solve_allocations,
+            solve_allocated,
+            solve_peak,
+            r.error.unwrap_or_default(),
+        ];
+        let fields = fields.iter().map(|f| csv_field(f)).collect::<Vec<_>>();
+        lines.push(fields.join(","));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use super::{to_csv, to_json};
+    use crate::memory::MemoryStats;
+    use crate::runner::DayReport;
+    use crate::utils::{Answer, Solution};
+    use crate::DayRun;
+
+    fn reports() -> Vec<DayReport> {
+        let solution = Solution {
+            part: 'b',
+            answer: Ok(Answer::lines(["#.", "\"x\""])),
+            elapsed: Duration::from_nanos(20),
+            memory: Some(MemoryStats {
+                allocations: 2,
+                allocated_bytes: 64,
+                peak_bytes: 48,
+            }),
+        };
+        vec![
+            DayReport {
+                year: 2022,
+                day: 10,
+                input: PathBuf::from("data/2022/day10.txt"),
+                run: Ok(DayRun {
+                    parse_elapsed: Duration::from_nanos(5),
+                    parse_memory: None,
+                    solutions: vec![solution],
+                }),
+            },
+            DayReport {
+                year: 2022,
+                day: 11,
+                input: PathBuf::from("data/2022/day11.txt"),
+                run: Err("no such file".to_owned()),
+            },
+        ]
+    }
+
+    #[test]
+    fn json_records() {
+        assert_eq!(
+            to_json(&reports()),
+            "[\n  {\"year\": 2022, \"day\": 10, \"part\": \"b\", \
+             \"input\": \"data/2022/day10.txt\", \
+             \"answer\": \"#.\\n\\\"x\\\"\", \"parse_ns\": 5, \"solve_ns\": 20, \
+             \"parse_memory\": null, \"solve_memory\": {\"allocations\": 2, \
+             \"allocated_bytes\": 64, \"peak_bytes\": 48}, \"error\": null},\n  \
+             {\"year\": 2022, \"day\": 11, \"part\": null, \"input\": \"data/2022/day11.txt\", \
+             \"answer\": null, \
+             \"parse_ns\": null, \"solve_ns\": null, \"parse_memory\": null, \
+             \"solve_memory\": null, \"error\": \"no such file\"}\n]"
+        );
+        assert_eq!(to_json(&[]), "[]");
+    }
+
+    /// Integer answers are numbers, and parts without a puzzle `null`.
+    #[test]
+    fn json_answers() {
+        let solution = |part, answer| Solution {
+            part,
+            answer: Ok(answer),
+            elapsed: Duration::from_nanos(20),
+            memory: None,
+        };
+        let report = |day, answer| DayReport {
+            year: 2022,
+            day,
+            input: PathBuf::from(format!("data/2022/day{}.txt", day)),
+            run: Ok(DayRun {
+                parse_elapsed: Duration::from_nanos(5),
+                parse_memory: None,
+                solutions: vec![solution('a', Answer::Integer(-3)), solution('b', answer)],
+            }),
+        };
+        let reports = [
+            report(24, Answer::NoSolution),
+            report(25, Answer::NotApplicable),
+        ];
+        let json: serde_json::Value = serde_json::from_str(&to_json(&reports)).unwrap();
+        let answers = json.as_array().unwrap().iter().map(|r| &r["answer"]).collect::<Vec<_>>();
+        assert_eq!(answers[0].as_i64(), Some(-3));
+        assert_eq!(answers[1].as_str(), Some("no solution"));
+        assert_eq!(answers[2].as_i64(), Some(-3));
+        assert!(answers[3].is_null());
+        assert_eq!(json[3]["solve_ns"].as_u64(), Some(20));
+        let csv = to_csv(&reports);
+        assert_eq!(csv.lines().nth(4), Some("2022,25,b,data/2022/day25.txt,,5,20,,,,,,,"));
+    }
+
+    #[test]
+    fn csv_records() {
+        assert_eq!(
+            to_csv(&reports()),
+            "year,day,part,input,answer,parse_ns,solve_ns,\
+             parse_allocations,parse_allocated_bytes,parse_peak_bytes,\
+             solve_allocations,solve_allocated_bytes,solve_peak_bytes,error\n\
+             2022,10,b,data/2022/day10.txt,\"#.\n\"\"x\"\"\",5,20,,,,2,64,48,\n\
+             2022,11,,data/2022/day11.txt,,,,,,,,,,no such file"
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    collections::HashMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// A puzzle constant of a day, which can be overridden with
+/// `--param name=value` or in a `.params` file next to the input.
+pub struct ParamSpec {
+    pub name: &'static str,
+    pub default: i64,
+    /// Smallest accepted value.
+    pub min: i64,
+    /// Largest accepted value, low enough for the solver not to overflow.
+    pub max: i64,
+    pub description: &'static str,
+}
+
+/// An override given as `name=value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub value: i64,
+}
+
+impl FromStr for Param {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected name=value, found {:?}", s))?;
+        let value = value
+            .trim()
+            .replace('_', "")
+            .parse()
+            .map_err(|_| format!("expected an integer value, found {:?}", value))?;
+        Ok(Param {
+            name: name.trim().to_owned(),
+            value,
+        })
+    }
+}
+
+/// Parameters of an input live next to it, e.g. `data/2022/day15-test.params`
+/// for `data/2022/day15-test.txt`, with one `name=value` per line.
+pub fn params_path(input: &Path) -> PathBuf {
+    input.with_extension("params")
+}
+
+pub fn parse_params(content: &str) -> Result<Vec<Param>, String> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| line.parse().map_err(|e| format!("line {}: {}", i + 1, e)))
+        .collect()
+}
+
+/// Reads the parameters stored next to `input`, if there are any.
+pub fn read_params(input: &Path) -> io::Result<Vec<Param>> {
+    let path = params_path(input);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    parse_params(&content).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    Unknown(String),
+    TooSmall { name: String, value: i64, min: i64 },
+    TooLarge { name: String, value: i64, max: i64 },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamError::Unknown(name) => write!(f, "unknown parameter {}", name),
+            ParamError::TooSmall { name, value, min } => {
+                write!(
+                    f,
+                    "parameter {} is {}, expected at least {}",
+                    name, value, min
+                )
+            }
+            ParamError::TooLarge { name, value, max } => {
+                write!(
+                    f,
+                    "parameter {} is {}, expected at most {}",
+                    name, value, max
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+/// The values of the parameters of a day: the defaults, with overrides applied.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    values: HashMap<&'static str, i64>,
+}
+
+impl Params {
+    /// Later overrides win over earlier ones.
+    pub fn resolve(specs: &[ParamSpec], overrides: &[Param]) -> Result<Self, ParamError> {
+        let mut values: HashMap<_, _> = specs.iter().map(|s| (s.name, s.default)).collect();
+        for param in overrides {
+            let spec = specs
+                .iter()
+                .find(|s| s.name == param.name)
+                .ok_or_else(|| ParamError::Unknown(param.name.clone()))?;
+            if param.value < spec.min {
+                return Err(ParamError::TooSmall {
+                    name: param.name.clone(),
+                    value: param.value,
+                    min: spec.min,
+                });
+            }
+            if param.value > spec.max {
+                return Err(ParamError::TooLarge {
+                    name: param.name.clone(),
+                    value: param.value,
+                    max: spec.max,
+                });
+            }
+            values.insert(spec.name, param.value);
+        }
+        Ok(Params { values })
+    }
+
+    /// Panics if the day did not declare `name`, or if its bounds do not
+    /// make every value fit in `T`.
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> T {
+        let value = *self
+            .values
+            .get(name)
+            .unwrap_or_else(|| panic!("undeclared parameter {}", name));
+        T::try_from(value).unwrap_or_else(|_| panic!("parameter {} does not fit: {}", name, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_params, Param, ParamError, ParamSpec, Params};
+
+    const SPECS: &[ParamSpec] = &[
+        ParamSpec {
+            name: "rounds",
+            default: 20,
+            min: 0,
+            max: 1_000,
+            description: "",
+        },
+        ParamSpec {
+            name: "row",
+            default: 10,
+            min: i64::MIN,
+            max: i64::MAX,
+            description: "",
+        },
+    ];
+
+    #[test]
+    fn parse_overrides() {
+        let param = "rounds=10_000".parse::<Param>().unwrap();
+        assert_eq!((param.name.as_str(), param.value), ("rounds", 10_000));
+        assert!("rounds".parse::<Param>().is_err());
+        assert!("rounds=many".parse::<Param>().is_err());
+        let params = parse_params("# example\nrow=-3\n\nrounds = 2\n").unwrap();
+        assert_eq!(params.len(), 2);
+        assert!(parse_params("row\n").is_err());
+    }
+
+    #[test]
+    fn resolve_with_defaults() {
+        let overrides = ["row=-3", "row=4"].map(|s| s.parse().unwrap());
+        let params = Params::resolve(SPECS, &overrides).unwrap();
+        assert_eq!(params.get::<usize>("rounds"), 20);
+        assert_eq!(params.get::<i64>("row"), 4);
+    }
+
+    #[test]
+    fn resolve_invalid() {
+        let unknown = ["knots=3".parse().unwrap()];
+        assert_eq!(
+            Params::resolve(SPECS, &unknown).unwrap_err(),
+            ParamError::Unknown("knots".to_owned())
+        );
+        let negative = ["rounds=-1".parse().unwrap()];
+        assert!(Params::resolve(SPECS, &negative).is_err());
+        let huge = ["rounds=1001".parse().unwrap()];
+        assert_eq!(
+            Params::resolve(SPECS, &huge).unwrap_err().to_string(),
+            "parameter rounds is 1001, expected at most 1000"
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+use crate::grid::{Grid, Point};
+
+/// Nodes connected by weighted moves. Closures returning the neighbors of a
+/// node are graphs, so searches work over any state type.
+pub trait Graph<N> {
+    /// The nodes reachable from `node` in one move, with the cost of each
+    /// move. Breadth-first searches ignore the costs.
+    fn neighbors(&self, node: &N) -> Vec<(N, u64)>;
+}
+
+impl<N, F> Graph<N> for F
+where
+    F: Fn(&N) -> Vec<(N, u64)>,
+{
+    fn neighbors(&self, node: &N) -> Vec<(N, u64)> {
+        self(node)
+    }
+}
+
+/// The neighbors of a point holding a value, in the topology of the grid,
+/// each one step away.
+impl<T> Graph<Point> for Grid<T> {
+    fn neighbors(&self, node: &Point) -> Vec<(Point, u64)> {
+        self.get_neighbors(node, false)
+            .into_iter()
+            .filter(|p| self.contains(p))
+            .map(|p| (p, 1))
+            .collect()
+    }
+}
+
+/// The outcome of a search: the cost of the cheapest path found to every
+/// reached node, and the goal it stopped at, if any. Costs are final for
+/// the goal and every node that was closer to the sources.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    costs: HashMap<N, u64>,
+    parents: HashMap<N, N>,
+    pub goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new<I: IntoIterator<Item = N>>(sources: I) -> Self {
+        Search {
+            costs: sources.into_iter().map(|node| (node, 0)).collect(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// Records a path to `node` through `parent` if it is the cheapest one.
+    fn relax(&mut self, parent: &N, node: N, cost: u64) -> bool {
+        if self.costs.get(&node).is_some_and(|&known| known <= cost) {
+            return false;
+        }
+        self.costs.insert(node.clone(), cost);
+        self.parents.insert(node, parent.clone());
+        true
+    }
+
+    pub fn cost(&self, node: &N) -> Option<u64> {
+        self.costs.get(node).copied()
+    }
+
+    /// The cost of the path to the goal, `None` if no goal was reached.
+    pub fn goal_cost(&self) -> Option<u64> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    /// The nodes of the path from a source to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.costs.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The path to the goal, `None` if no goal was reached.
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+
+    /// Every node reached by the search, sources included.
+    pub fn reached(&self) -> impl Iterator<Item = &N> {
+        self.costs.keys()
+    }
+}
+
+/// Breadth-first search from all the `sources` at once, counting moves
+/// rather than their costs, until a node satisfying `is_goal` is reached or
+/// every reachable node is visited.
+pub fn bfs<N, G, I, F>(graph: &G, sources: I, is_goal: F) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    G: Graph<N>,
+    I: IntoIterator<Item = N>,
+    F: Fn(&N) -> bool,
+{
+    let mut search = Search::new(sources);
+    let mut queue = search.costs.keys().cloned().collect::<VecDeque<_>>();
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let steps = search.costs[&node] + 1;
+        for (neighbor, _) in graph.neighbors(&node) {
+            if !search.costs.contains_key(&neighbor) {
+                search.relax(&node, neighbor.clone(), steps);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    search
+}
+
+/// Cheapest paths from all the `sources` at once, until a node satisfying
+/// `is_goal` is reached or every reachable node is visited.
+pub fn dijkstra<N, G, I, F>(graph: &G, sources: I, is_goal: F) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    G: Graph<N>,
+    I: IntoIterator<Item = N>,
+    F: Fn(&N) -> bool,
+{
+    astar(graph, sources, |_| 0, is_goal)
+}
+
+/// Cheapest paths from the `sources` to a node satisfying `is_goal`, guided
+/// by `heuristic`, which must not overestimate the cost left to a goal.
+pub fn astar<N, G, I, H, F>(graph: &G, sources: I, heuristic: H, is_goal: F) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    G: Graph<N>,
+    I: IntoIterator<Item = N>,
+    H: Fn(&N) -> u64,
+    F: Fn(&N) -> bool,
+{
+    let mut search = Search::new(sources);
+    let mut queue = search
+        .costs
+        .keys()
+        .map(|node| Queued::new(node.clone(), 0, heuristic(node)))
+        .collect::<BinaryHeap<_>>();
+    while let Some(Queued { node, cost, .. }) = queue.pop() {
+        if search.costs[&node] < cost {
+            // A cheaper path to this node was queued after this one.
+            continue;
+        }
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for (neighbor, step) in graph.neighbors(&node) {
+            let cost = cost + step;
+            if search.relax(&node, neighbor.clone(), cost) {
+                let estimate = heuristic(&neighbor);
+                queue.push(Queued::new(neighbor, cost, estimate));
+            }
+        }
+    }
+    search
+}
+
+/// A node waiting in the queue of `astar`, the one with the lowest
+/// estimated total cost coming out first.
+struct Queued<N> {
+    node: N,
+    cost: u64,
+    estimate: u64,
+}
+
+impl<N> Queued<N> {
+    fn new(node: N, cost: u64, heuristic: u64) -> Self {
+        Queued {
+            node,
+            cost,
+            estimate: cost + heuristic,
+        }
+    }
+}
+
+impl<N> PartialEq for Queued<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N> Eq for Queued<N> {}
+
+impl<N> PartialOrd for Queued<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Queued<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+    use crate::grid::{Grid, Point};
+
+    fn maze() -> Grid<char> {
+        let mut grid = Grid::from(&[
+            String::from("..#."),
+            String::from(".##."),
+            String::from("...."),
+        ])
+        .unwrap();
+        grid.retain(|_, c| *c == '.');
+        grid
+    }
+
+    #[test]
+    fn bfs_on_grid() {
+        let grid = maze();
+        let end = Point::new(3, 0);
+        let search = bfs(&grid, [Point::new(0, 0)], |p| *p == end);
+        assert_eq!(search.goal_cost(), Some(7));
+        assert_eq!(search.goal_path().unwrap().len(), 8);
+        let search = bfs(&grid, [Point::new(0, 0), Point::new(3, 2)], |p| *p == end);
+        assert_eq!(search.goal_cost(), Some(2));
+        let search = bfs(&grid, [Point::new(0, 0)], |_| false);
+        assert_eq!(search.goal, None);
+        assert_eq!(search.reached().count(), 9);
+    }
+
+    #[test]
+    fn weighted_searches() {
+        let graph = |node: &char| match node {
+            'a' => vec![('b', 1), ('c', 4)],
+            'b' => vec![('c', 2), ('d', 5)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        };
+        let search = dijkstra(&graph, ['a'], |node| *node == 'd');
+        assert_eq!(search.goal_cost(), Some(4));
+        assert_eq!(search.goal_path(), Some(vec!['a', 'b', 'c', 'd']));
+        let search = bfs(&graph, ['a'], |node| *node == 'd');
+        assert_eq!(search.goal_path(), Some(vec!['a', 'b', 'd']));
+        let search = astar(&graph, ['a'], |_| 0, |_| false);
+        assert_eq!(search.cost(&'c'), Some(3));
+    }
+
+    #[test]
+    fn astar_on_grid() {
+        let grid = maze();
+        let end = Point::new(3, 0);
+        let search = astar(
+            &grid,
+            [Point::new(0, 0)],
+            |p| p.manhattan_distance(&end),
+            |p| *p == end,
+        );
+        assert_eq!(search.goal_cost(), Some(7));
+        assert_eq!(search.cost(&Point::new(0, 2)), Some(2));
+        assert_eq!(search.path(&Point::new(2, 0)), None);
+    }
+}
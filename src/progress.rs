@@ -0,0 +1,106 @@
+use std::{
+    cell::Cell,
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// Long loops report at most this often, to keep stderr readable.
+const REPORT_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Where a solver reports the progress of its long loops, printed on stderr
+/// with `--verbose`. When disabled, reporting costs a single check.
+pub struct Progress {
+    /// `None` when progress is not reported.
+    label: Option<String>,
+    start: Instant,
+    last_report: Cell<Option<Instant>>,
+}
+
+impl Progress {
+    /// Reports the progress, prefixed by `label`.
+    pub fn new<S: Into<String>>(label: S) -> Self {
+        Progress {
+            label: Some(label.into()),
+            start: Instant::now(),
+            last_report: Cell::new(None),
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Progress {
+            label: None,
+            start: Instant::now(),
+            last_report: Cell::new(None),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.label.is_some()
+    }
+
+    /// Reports that `done` of `total` iterations are done, along with the
+    /// size of the frontier of searches.
+    pub fn report(&self, done: u64, total: u64, frontier: Option<usize>) {
+        let Some(label) = &self.label else {
+            return;
+        };
+        let now = Instant::now();
+        if let Some(last) = self.last_report.get() {
+            if now - last < REPORT_INTERVAL {
+                return;
+            }
+        }
+        self.last_report.set(Some(now));
+        let progress = format_progress(done, total, frontier, now - self.start);
+        eprintln!("[{}] {}", label, progress);
+    }
+
+    /// Prints a message, e.g. an intermediate result, whatever the interval.
+    pub fn trace<T: fmt::Display>(&self, message: T) {
+        if let Some(label) = &self.label {
+            eprintln!("[{}] {}", label, message);
+        }
+    }
+}
+
+fn format_progress(done: u64, total: u64, frontier: Option<usize>, elapsed: Duration) -> String {
+    let percent = done as f64 * 100.0 / total.max(1) as f64;
+    let mut progress = format!("{}/{} ({:.0}%)", done, total, percent);
+    if let Some(frontier) = frontier {
+        progress.push_str(&format!(", frontier {}", frontier));
+    }
+    progress.push_str(&format!(", {:.2?} elapsed", elapsed));
+    if done > 0 && done < total {
+        let eta = elapsed.mul_f64((total - done) as f64 / done as f64);
+        progress.push_str(&format!(", ETA {:.2?}", eta));
+    }
+    progress
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_progress, Progress};
+
+    #[test]
+    fn format_with_eta() {
+        assert_eq!(
+            format_progress(1, 4, Some(120), Duration::from_secs(2)),
+            "1/4 (25%), frontier 120, 2.00s elapsed, ETA 6.00s"
+        );
+        assert_eq!(
+            format_progress(4, 4, None, Duration::from_secs(2)),
+            "4/4 (100%), 2.00s elapsed"
+        );
+    }
+
+    #[test]
+    fn disabled_progress() {
+        let progress = Progress::disabled();
+        assert!(!progress.is_enabled());
+        progress.report(1, 2, None);
+        assert!(progress.last_report.get().is_none());
+        assert!(Progress::new("day 16 part b").is_enabled());
+    }
+}
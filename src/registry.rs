@@ -0,0 +1,152 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use crate::params::{self, Param, ParamSpec, Params};
+use crate::utils::{Day, ParseError};
+use crate::{year2022, Error};
+
+/// Parses the input of a day, with the values of its parameters.
+pub type Build = fn(&str, &Params) -> Result<Box<dyn Day>, ParseError>;
+
+/// Everything the runner needs to know about a day, declared as `INFO`
+/// in each day module.
+pub struct DayInfo {
+    pub day: u8,
+    pub title: &'static str,
+    /// What parts A and B compute.
+    pub parts: [&'static str; 2],
+    /// Puzzle constants that can be overridden, empty for most days.
+    pub params: &'static [ParamSpec],
+    pub build: Build,
+}
+
+impl DayInfo {
+    /// Parses `input` with the default parameters.
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Day>, Error> {
+        self.parse_with(input, &[])
+    }
+
+    /// Parses `input` with the given parameters, later ones winning.
+    pub fn parse_with(&self, input: &str, params: &[Param]) -> Result<Box<dyn Day>, Error> {
+        let params = Params::resolve(self.params, params)?;
+        Ok((self.build)(input, &params)?)
+    }
+
+    pub fn read<R: Read>(&self, mut reader: R, params: &[Param]) -> Result<Box<dyn Day>, Error> {
+        let mut input = String::new();
+        reader
+            .read_to_string(&mut input)
+            .map_err(|e| Error::Io(e.to_string()))?;
+        self.parse_with(&input, params)
+    }
+
+    /// Reads the input from a file, reporting parse errors in that file.
+    /// The parameters stored next to it come first, like on the command
+    /// line, then `params`.
+    pub fn open<P: AsRef<Path>>(&self, path: P, params: &[Param]) -> Result<Box<dyn Day>, Error> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| Error::Io(format!("{}: {}", path.display(), e)))?;
+        let mut all_params = params::read_params(path).map_err(|e| Error::Io(e.to_string()))?;
+        all_params.extend_from_slice(params);
+        self.read(file, &all_params)
+            .map_err(|e| e.in_file(path))
+    }
+}
+
+/// The days solved for a year, declared as `DAYS` in its `yearN` module.
+pub struct YearInfo {
+    pub year: u16,
+    pub days: &'static [DayInfo],
+}
+
+/// Ordered by year, the last one being the default.
+pub const YEARS: &[YearInfo] = &[YearInfo {
+    year: 2022,
+    days: year2022::DAYS,
+}];
+
+/// Advent of Code has a puzzle each day from December 1 to 25.
+pub const LAST_DAY: u8 = 25;
+
+pub fn latest_year() -> u16 {
+    YEARS.last().expect("at least one year is registered").year
+}
+
+pub fn find_year(year: u16) -> Option<&'static YearInfo> {
+    YEARS.iter().find(|info| info.year == year)
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static DayInfo> {
+    find_year(year)?.days.iter().find(|info| info.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{find, YEARS};
+    use crate::answers::{self, Check};
+    use crate::progress::Progress;
+    use crate::utils::Answer;
+    use crate::Error;
+
+    #[test]
+    fn days_are_in_order() {
+        for year in YEARS {
+            for pair in year.days.windows(2) {
+                assert!(pair[0].day < pair[1].day);
+            }
+        }
+        assert!(YEARS.windows(2).all(|pair| pair[0].year < pair[1].year));
+    }
+
+    #[test]
+    fn parse_from_str_and_reader() {
+        let info = find(2022, 1).unwrap();
+        let input = "1000\n2000\n\n4000\n";
+        let day = info.parse(input).unwrap();
+        assert_eq!(day.solve_a(&Progress::disabled()), Answer::Integer(4000));
+        let day = info.read(input.as_bytes(), &[]).unwrap();
+        assert_eq!(day.solve_b(&Progress::disabled()), Answer::Integer(7000));
+        assert!(matches!(info.parse("x"), Err(Error::Parse(_))));
+    }
+
+    #[test]
+    fn open_file() {
+        let info = find(2022, 1).unwrap();
+        let day = info.open("data/2022/day1-test.txt", &[]).unwrap();
+        assert_eq!(day.solve_a(&Progress::disabled()), Answer::Integer(24000));
+        assert!(matches!(
+            info.open("data/2022/missing.txt", &[]),
+            Err(Error::Io(_))
+        ));
+        let params = ["row=10".parse().unwrap()];
+        let info = find(2022, 15).unwrap();
+        let day = info.open("data/2022/day15-test.txt", &params).unwrap();
+        assert_eq!(day.solve_a(&Progress::disabled()), Answer::Integer(26));
+    }
+
+    /// `open` applies the `.params` file of an input like the command line
+    /// does, so it gives the answers `--check` expects.
+    #[test]
+    fn open_with_stored_params() {
+        for (day, name) in [(15, "day15-test"), (23, "day23-test-small")] {
+            let path = Path::new("data/2022").join(format!("{}.txt", name));
+            let solver = find(2022, day).unwrap().open(&path, &[]).unwrap();
+            let expected = answers::read_answers(&path).unwrap();
+            for part in ['a', 'b'] {
+                let solution = solver.solution(part, &Progress::disabled());
+                let check = answers::check(&solution, &expected);
+                assert!(matches!(check, Check::Ok), "{} part {}", name, part);
+            }
+        }
+    }
+
+    #[test]
+    fn find_days() {
+        assert_eq!(find(2022, 7).map(|info| info.day), Some(7));
+        assert!(find(2022, 26).is_none());
+        assert!(find(2015, 1).is_none());
+    }
+}
@@ -0,0 +1,370 @@
+use std::{
+    collections::BTreeMap,
+    panic,
+    path::PathBuf,
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use crate::memory::MemoryStats;
+use crate::registry::{DayInfo, YearInfo, LAST_DAY};
+use crate::utils::Solution;
+use crate::DayRun;
+
+/// The days given on the command line, checked against a year with `days`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Days(Vec<u8>),
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    let day = s
+        .trim()
+        .parse::<u8>()
+        .map_err(|_| format!("invalid day: {}", s))?;
+    if !(1..=LAST_DAY).contains(&day) {
+        return Err(format!("day {} is not between 1 and {}", day, LAST_DAY));
+    }
+    Ok(day)
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    /// Accepts `all`, single days (`3`), ranges (`1..5`, `1..=10`)
+    /// and comma-separated combinations of those (`1..=3,7,12`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+        let mut days = vec![];
+        for part in s.split(',') {
+            if let Some((start, end)) = part.split_once("..=") {
+                days.extend(parse_day(start)?..=parse_day(end)?);
+            } else if let Some((start, end)) = part.split_once("..") {
+                days.extend(parse_day(start)?..parse_day(end)?);
+            } else {
+                days.push(parse_day(part)?);
+            }
+        }
+        days.sort();
+        days.dedup();
+        if days.is_empty() {
+            return Err(format!("no days selected by {}", s));
+        }
+        Ok(DaySelection::Days(days))
+    }
+}
+
+impl DaySelection {
+    /// The selected days, failing if one of them is not solved for `year`.
+    pub fn days(&self, year: &YearInfo) -> Result<Vec<u8>, String> {
+        let solved = year.days.iter().map(|info| info.day).collect::<Vec<_>>();
+        match self {
+            DaySelection::All => Ok(solved),
+            DaySelection::Days(days) => match days.iter().find(|day| !solved.contains(day)) {
+                Some(day) => Err(format!("day {} of {} is not implemented", day, year.year)),
+                None => Ok(days.clone()),
+            },
+        }
+    }
+}
+
+pub fn parse_part(s: &str) -> Result<char, String> {
+    match s {
+        "a" | "A" => Ok('a'),
+        "b" | "B" => Ok('b'),
+        _ => Err(format!("unknown part {}, expected a or b", s)),
+    }
+}
+
+/// Parses durations such as `30s`, `500ms`, `1.5s` or `2m`.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .ok_or_else(|| format!("missing unit in {}, expected ms, s or m", s))?;
+    let (value, unit) = s.split_at(split);
+    let value = value
+        .parse::<f64>()
+        .map_err(|_| format!("invalid duration: {}", s))?;
+    let seconds = match unit {
+        "ms" => value / 1000.0,
+        "s" => value,
+        "m" => value * 60.0,
+        _ => return Err(format!("unknown unit {}, expected ms, s or m", unit)),
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("invalid duration: {}", s))
+}
+
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub input: PathBuf,
+    pub run: Result<DayRun, String>,
+}
+
+impl DayReport {
+    /// Whether the day, or one of its parts, failed.
+    pub fn failed(&self) -> bool {
+        match &self.run {
+            Ok(run) => run.solutions.iter().any(|s| s.answer.is_err()),
+            Err(_) => true,
+        }
+    }
+}
+
+/// Runs `f`, turning a panic into an error holding the panic message
+/// so that one broken day does not stop the others from running.
+pub fn run_isolated<T, F>(f: F) -> Result<T, String>
+where
+    F: FnOnce() -> Result<T, String>,
+{
+    panic::catch_unwind(panic::AssertUnwindSafe(f)).unwrap_or_else(|e| {
+        if let Some(s) = e.downcast_ref::<&str>() {
+            Err(format!("panicked: {}", s))
+        } else if let Some(s) = e.downcast_ref::<String>() {
+            Err(format!("panicked: {}", s))
+        } else {
+            Err("panicked".to_owned())
+        }
+    })
+}
+
+/// Calls `f` on the items from `jobs` worker threads, and `on_result` with
+/// the results in the order of the items, each one as soon as it and all
+/// the previous ones are available.
+pub fn run_parallel<T, R, F, G>(items: &[T], jobs: usize, f: F, mut on_result: G)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    G: FnMut(R),
+{
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                if sender.send((i, f(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut expected = 0;
+        for (i, result) in receiver {
+            pending.insert(i, result);
+            while let Some(result) = pending.remove(&expected) {
+                on_result(result);
+                expected += 1;
+            }
+        }
+    });
+}
+
+fn format_cost(elapsed: Duration, memory: Option<MemoryStats>) -> String {
+    match memory {
+        Some(memory) => format!("{:.2?}, {}", elapsed, memory),
+        None => format!("{:.2?}", elapsed),
+    }
+}
+
+pub fn print_solution(solution: &Solution) {
+    let answer = match &solution.answer {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("FAILED, {}", e),
+    };
+    println!(
+        "Part {} ({}):\n{}",
+        solution.part.to_ascii_uppercase(),
+        format_cost(solution.elapsed, solution.memory),
+        answer
+    );
+}
+
+/// Prints the cost of parsing, only when memory is measured since
+/// the parse time alone is not worth a line.
+pub fn print_parse(run: &DayRun) {
+    if run.parse_memory.is_some() {
+        let cost = format_cost(run.parse_elapsed, run.parse_memory);
+        println!("Parse ({})", cost);
+    }
+}
+
+pub fn print_days(days: &[DayInfo]) {
+    for info in days {
+        println!("Day {}: {}", info.day, info.title);
+        for (part, description) in ['a', 'b'].iter().zip(info.parts) {
+            println!("  {}: {}", part, description);
+        }
+        for param in info.params {
+            println!(
+                "  --param {}={}: {}",
+                param.name, param.default, param.description
+            );
+        }
+    }
+}
+
+fn summarize_answer(answer: &str) -> String {
+    let mut lines = answer.lines();
+    let first = lines.next().unwrap_or("");
+    match lines.count() {
+        0 => first.to_owned(),
+        n => format!("{} (+{} lines)", first, n),
+    }
+}
+
+pub fn print_summary(reports: &[DayReport]) {
+    let mut rows = vec![];
+    for report in reports {
+        match &report.run {
+            Ok(run) => rows.extend(run.solutions.iter().map(|s| {
+                (
+                    report.day.to_string(),
+                    s.part.to_string(),
+                    match &s.answer {
+                        Ok(answer) => summarize_answer(&answer.to_string()),
+                        Err(e) => summarize_answer(e),
+                    },
+                    format!("{:.2?}", s.elapsed),
+                )
+            })),
+            Err(e) => rows.push((
+                report.day.to_string(),
+                "-".to_owned(),
+                summarize_answer(e),
+                "-".to_owned(),
+            )),
+        }
+    }
+
+    let header = (
+        "Day".to_owned(),
+        "Part".to_owned(),
+        "Answer".to_owned(),
+        "Time".to_owned(),
+    );
+    let width = |f: fn(&(String, String, String, String)) -> &String| {
+        rows.iter()
+            .chain([&header])
+            .map(|r| f(r).chars().count())
+            .max()
+            .unwrap()
+    };
+    let widths = (
+        width(|r| &r.0),
+        width(|r| &r.1),
+        width(|r| &r.2),
+        width(|r| &r.3),
+    );
+    let print_row = |r: &(String, String, String, String)| {
+        println!(
+            "{:>w0$} | {:<w1$} | {:<w2$} | {:>w3$}",
+            r.0,
+            r.1,
+            r.2,
+            r.3,
+            w0 = widths.0,
+            w1 = widths.1,
+            w2 = widths.2,
+            w3 = widths.3,
+        );
+    };
+
+    print_row(&header);
+    println!(
+        "{}-+-{}-+-{}-+-{}",
+        "-".repeat(widths.0),
+        "-".repeat(widths.1),
+        "-".repeat(widths.2),
+        "-".repeat(widths.3)
+    );
+    rows.iter().for_each(print_row);
+
+    let total: Duration = reports
+        .iter()
+        .filter_map(|r| r.run.as_ref().ok())
+        .flat_map(|run| &run.solutions)
+        .map(|s| s.elapsed)
+        .sum();
+    let failed = reports.iter().filter(|r| r.failed()).count();
+    println!("\nTotal: {:.2?}, {} day(s) failed", total, failed);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::{parse_duration, parse_part, run_parallel, DaySelection};
+    use crate::registry;
+
+    fn parse(s: &str) -> Result<Vec<u8>, String> {
+        let year = registry::find_year(2022).unwrap();
+        s.parse::<DaySelection>()?.days(year)
+    }
+
+    #[test]
+    fn parse_selection() {
+        assert_eq!(parse("7"), Ok(vec![7]));
+        assert_eq!(parse("1..4"), Ok(vec![1, 2, 3]));
+        assert_eq!(parse("1..=10").unwrap().len(), 10);
+        assert_eq!(parse("12,3,7,3"), Ok(vec![3, 7, 12]));
+        assert_eq!(parse("1..=2,20..=21"), Ok(vec![1, 2, 20, 21]));
+        assert_eq!(parse("all").unwrap().len(), 25);
+    }
+
+    #[test]
+    fn parse_invalid_selection() {
+        assert!(parse("0").is_err());
+        assert!(parse("26").is_err());
+        assert!(parse("3..3").is_err());
+        assert!(parse("foo").is_err());
+    }
+
+    #[test]
+    fn parse_durations() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert!(parse_duration("10").is_err());
+        assert!(parse_duration("3h").is_err());
+        assert!(parse_duration("-1s").is_err());
+    }
+
+    #[test]
+    fn results_in_order() {
+        let items = (0..20u64).collect::<Vec<_>>();
+        let mut results = vec![];
+        run_parallel(
+            &items,
+            4,
+            |n| {
+                thread::sleep(Duration::from_millis(20 - n));
+                n * 2
+            },
+            |r| results.push(r),
+        );
+        assert_eq!(results, items.iter().map(|n| n * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn parse_parts() {
+        assert_eq!(parse_part("a"), Ok('a'));
+        assert_eq!(parse_part("B"), Ok('b'));
+        assert!(parse_part("c").is_err());
+    }
+}
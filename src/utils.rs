@@ -1,87 +1,394 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
-use std::time::Instant;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 use regex::{Captures, Regex};
 
-pub trait Day<A: std::fmt::Display = u64, B: std::fmt::Display = u64> {
-    fn solve_a(&self) -> A;
-    fn solve_b(&self) -> B;
+use crate::memory::{self, MemoryStats};
+use crate::progress::Progress;
 
-    fn output_solutions(&self) {
-        let before_a = Instant::now();
-        let result_a = self.solve_a();
-        println!("Part A ({:.2?}):\n{}", before_a.elapsed(), result_a);
-        let before_b = Instant::now();
-        let result_b = self.solve_b();
-        println!("Part B ({:.2?}):\n{}", before_b.elapsed(), result_b);
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    Lines(Vec<String>),
+    /// The part has no puzzle, like the last part of the last day.
+    NotApplicable,
+    /// The puzzle has no solution for this input or these parameters.
+    NoSolution,
+}
+
+impl Answer {
+    pub fn lines<I, S>(lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Answer::Lines(lines.into_iter().map(Into::into).collect())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+            Answer::NotApplicable => write!(f, "-"),
+            Answer::NoSolution => write!(f, "no solution"),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::Integer(n.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Integer(n.into())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Solution {
+    pub part: char,
+    /// The error message when the part did not finish, e.g. it timed out.
+    pub answer: Result<Answer, String>,
+    pub elapsed: Duration,
+    /// Only measured with the `alloc-stats` feature.
+    pub memory: Option<MemoryStats>,
+}
+
+pub trait Day: Send + Sync {
+    /// The parts report the progress of their long loops to `progress`, if
+    /// they have any; `Progress::disabled()` reports nothing.
+    fn solve_a(&self, progress: &Progress) -> Answer;
+    fn solve_b(&self, progress: &Progress) -> Answer;
+
+    fn solution(&self, part: char, progress: &Progress) -> Solution {
+        let before = Instant::now();
+        let (answer, memory) = memory::measure(|| match part {
+            'a' => self.solve_a(progress),
+            'b' => self.solve_b(progress),
+            _ => panic!("Unknown part: {}", part),
+        });
+        Solution {
+            part,
+            answer: Ok(answer),
+            elapsed: before.elapsed(),
+            memory,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new<E: Into<String>, F: Into<String>>(expected: E, found: F) -> Self {
+        Self {
+            file: None,
+            line: None,
+            column: None,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Helpers report positions relative to the string they were given,
+    /// so callers only fill in what is still unknown.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    pub fn in_file<P: AsRef<Path>>(mut self, file: P) -> Self {
+        self.file.get_or_insert(file.as_ref().to_path_buf());
+        self
+    }
+
+    /// Shifts the column by `offset`, for errors found in a substring.
+    pub fn shift_column(mut self, offset: usize) -> Self {
+        self.column = self.column.map(|c| c + offset);
+        self
     }
 }
 
-pub fn read_lines<P>(filepath: P) -> Vec<String>
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "{}:", line)?;
+        }
+        if let Some(column) = self.column {
+            write!(f, "{}:", column)?;
+        }
+        if self.file.is_some() || self.line.is_some() || self.column.is_some() {
+            write!(f, " ")?;
+        }
+        write!(f, "expected {}, found {:?}", self.expected, self.found)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Parses `s`, which starts at `column` (1-based) in the line it comes from.
+/// Types parsed with a `ParseError` keep theirs, moved to that column.
+pub fn parse_at<T>(s: &str, column: usize) -> Result<T, ParseError>
 where
-    P: AsRef<Path>,
+    T: std::str::FromStr,
+    T::Err: 'static,
 {
-    let file = File::open(filepath).unwrap();
-    let lines = io::BufReader::new(file).lines();
-    lines.map(|line| line.unwrap()).collect()
+    s.parse().map_err(|e| {
+        let e: Box<dyn std::any::Any> = Box::new(e);
+        match e.downcast::<ParseError>() {
+            Ok(e) => e.shift_column(column - 1).at_column(column),
+            Err(_) => ParseError::new(type_name::<T>(), s).at_column(column),
+        }
+    })
+}
+
+/// Where the puzzle input comes from: a file, or stdin when given `-`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    File(PathBuf),
+    Stdin,
 }
 
-pub fn split2<T, U>(s: &str, pat: &str) -> (T, U)
+impl Input {
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Input::File(path) => fs::read_to_string(path),
+            Input::Stdin => io::read_to_string(io::stdin()),
+        }
+    }
+
+    /// Name used to report errors, also stored in `ParseError::file`.
+    pub fn name(&self) -> PathBuf {
+        match self {
+            Input::File(path) => path.clone(),
+            Input::Stdin => PathBuf::from("<stdin>"),
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(Input::Stdin),
+            _ => Ok(Input::File(PathBuf::from(s))),
+        }
+    }
+}
+
+pub fn lines(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+/// Splits `s` on `pat` and returns an iterator of `(column, part)`.
+fn split_columns<'a>(s: &'a str, pat: &'a str) -> impl Iterator<Item = (usize, &'a str)> + 'a {
+    s.split(pat).scan(1, move |column, part| {
+        let start = *column;
+        *column += part.len() + pat.len();
+        Some((start, part))
+    })
+}
+
+fn next_part<'a, T>(
+    parts: &mut impl Iterator<Item = (usize, &'a str)>,
+    s: &str,
+    pat: &str,
+) -> Result<T, ParseError>
+where
+    T: std::str::FromStr,
+    T::Err: 'static,
+{
+    match parts.next() {
+        Some((column, part)) => parse_at(part, column),
+        None => Err(ParseError::new(format!("{:?}", pat), "end of line").at_column(s.len() + 1)),
+    }
+}
+
+pub fn split2<T, U>(s: &str, pat: &str) -> Result<(T, U), ParseError>
 where
     T: std::str::FromStr,
     U: std::str::FromStr,
-    <T as std::str::FromStr>::Err: std::fmt::Debug,
-    <U as std::str::FromStr>::Err: std::fmt::Debug,
+    T::Err: 'static,
+    U::Err: 'static,
 {
-    let mut parts = s.split(pat);
-    let a = parts.next().unwrap().parse().unwrap();
-    let b = parts.next().unwrap().parse().unwrap();
-    (a, b)
+    let mut parts = split_columns(s, pat);
+    let a = next_part(&mut parts, s, pat)?;
+    let b = next_part(&mut parts, s, pat)?;
+    Ok((a, b))
 }
 
-pub fn split3<T, U, V>(s: &str, pat: &str) -> (T, U, V)
+pub fn split3<T, U, V>(s: &str, pat: &str) -> Result<(T, U, V), ParseError>
 where
     T: std::str::FromStr,
     U: std::str::FromStr,
     V: std::str::FromStr,
-    <T as std::str::FromStr>::Err: std::fmt::Debug,
-    <U as std::str::FromStr>::Err: std::fmt::Debug,
-    <V as std::str::FromStr>::Err: std::fmt::Debug,
+    T::Err: 'static,
+    U::Err: 'static,
+    V::Err: 'static,
 {
-    let mut parts = s.split(pat);
-    let a = parts.next().unwrap().parse().unwrap();
-    let b = parts.next().unwrap().parse().unwrap();
-    let c = parts.next().unwrap().parse().unwrap();
-    (a, b, c)
+    let mut parts = split_columns(s, pat);
+    let a = next_part(&mut parts, s, pat)?;
+    let b = next_part(&mut parts, s, pat)?;
+    let c = next_part(&mut parts, s, pat)?;
+    Ok((a, b, c))
 }
 
-pub fn parse_lines<T>(lines: &[String]) -> Vec<T>
+/// Splits `s` on `pat` and parses every part.
+pub fn split_list<T>(s: &str, pat: &str) -> Result<Vec<T>, ParseError>
 where
     T: std::str::FromStr,
-    <T as std::str::FromStr>::Err: std::fmt::Debug,
+    T::Err: 'static,
 {
-    lines.iter().map(|l| l.parse().unwrap()).collect()
+    split_columns(s, pat)
+        .map(|(column, part)| parse_at(part, column))
+        .collect()
 }
 
-pub fn get_caps<'a>(re: &str, s: &'a str) -> Captures<'a> {
-    Regex::new(re).unwrap().captures(s).unwrap()
+/// Parses every line with `f`, adding the (1-based) line number to errors.
+pub fn parse_lines_with<T, F>(lines: &[String], mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| f(l).map_err(|e| e.at_line(i + 1)))
+        .collect()
 }
 
-pub fn captures<'t>(re: &str, s: &'t str) -> Captures<'t> {
-    Regex::new(re).unwrap().captures(s).unwrap()
+pub fn parse_lines<T>(lines: &[String]) -> Result<Vec<T>, ParseError>
+where
+    T: std::str::FromStr,
+    T::Err: 'static,
+{
+    parse_lines_with(lines, |l| parse_at(l, 1))
 }
 
-pub fn get_cap<T>(caps: &Captures, i: usize) -> T
+pub fn get_caps<'a>(re: &str, s: &'a str) -> Result<Captures<'a>, ParseError> {
+    Regex::new(re)
+        .unwrap()
+        .captures(s)
+        .ok_or_else(|| ParseError::new(format!("text matching /{}/", re), s).at_column(1))
+}
+
+pub fn captures<'t>(re: &str, s: &'t str) -> Result<Captures<'t>, ParseError> {
+    get_caps(re, s)
+}
+
+pub fn get_cap<T>(caps: &Captures, i: usize) -> Result<T, ParseError>
 where
     T: std::str::FromStr,
-    <T as std::str::FromStr>::Err: std::fmt::Debug,
+    T::Err: 'static,
 {
-    caps.get(i).unwrap().as_str().parse().unwrap()
+    let cap = caps.get(i).ok_or_else(|| {
+        ParseError::new(format!("capture group {}", i), "no match")
+            .at_column(caps.get(0).map_or(1, |m| m.start() + 1))
+    })?;
+    parse_at(cap.as_str(), cap.start() + 1)
 }
 
-pub fn get_cap_str(caps: &Captures, i: usize) -> String
-{
-    caps.get(i).unwrap().as_str().to_string()
+pub fn get_cap_str(caps: &Captures, i: usize) -> Result<String, ParseError> {
+    get_cap(caps, i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_cap, get_caps, parse_lines, split2, split3, Answer, ParseError};
+
+    #[test]
+    fn split_with_columns() {
+        assert_eq!(split3::<u8, u8, u8>("1,22,3", ","), Ok((1, 22, 3)));
+        let err = split3::<u8, u8, u8>("1,x,3", ",").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (Some(3), "u8"));
+        let err = split2::<String, u8>("abc", " ").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (Some(4), "\" \""));
+    }
+
+    /// A type parsed with a `ParseError` of its own.
+    #[derive(Debug, PartialEq)]
+    struct Range(u8, u8);
+
+    impl std::str::FromStr for Range {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (start, end) = split2(s, "-")?;
+            Ok(Range(start, end))
+        }
+    }
+
+    #[test]
+    fn nested_errors() {
+        assert_eq!(split2("2-4,6-8", ","), Ok((Range(2, 4), Range(6, 8))));
+        let err = split2::<Range, Range>("2-4,6-x", ",").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (Some(7), "u8"));
+        let err = split2::<Range, Range>("2-4,6", ",").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (Some(6), "\"-\""));
+    }
+
+    #[test]
+    fn parse_lines_with_line_numbers() {
+        let lines = ["1", "2", "three"].map(String::from);
+        let err = parse_lines::<u64>(&lines).unwrap_err();
+        assert_eq!(err.line, Some(3));
+        assert_eq!(
+            err.in_file("day1.txt").to_string(),
+            "day1.txt:3:1: expected u64, found \"three\""
+        );
+    }
+
+    #[test]
+    fn captures_with_columns() {
+        let caps = get_caps(r"x=(-?\d+), y=(\w+)", "at x=12, y=ab").unwrap();
+        assert_eq!(get_cap::<i64>(&caps, 1), Ok(12));
+        let err: ParseError = get_cap::<i64>(&caps, 2).unwrap_err();
+        assert_eq!(err.column, Some(12));
+        assert!(get_caps(r"x=(\d+)", "y=1").is_err());
+    }
+
+    #[test]
+    fn display_answers() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::lines(["#.", ".#"]).to_string(), "#.\n.#");
+        assert_eq!(Answer::NotApplicable.to_string(), "-");
+        assert_eq!(Answer::NoSolution.to_string(), "no solution");
+    }
 }
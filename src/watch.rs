@@ -0,0 +1,91 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often the watched files are polled.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Detects changes to a set of files and to the files of a set of
+/// directories by polling their modification times, which is enough for
+/// inputs edited by hand and needs no platform-specific notifications.
+pub struct Watcher {
+    files: Vec<PathBuf>,
+    dirs: Vec<PathBuf>,
+    snapshot: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl Watcher {
+    /// Files that do not exist yet are watched too, so that creating them
+    /// counts as a change.
+    pub fn new(files: Vec<PathBuf>, dirs: Vec<PathBuf>) -> Self {
+        let mut watcher = Watcher {
+            files,
+            dirs,
+            snapshot: BTreeMap::new(),
+        };
+        watcher.snapshot = watcher.scan();
+        watcher
+    }
+
+    fn scan(&self) -> BTreeMap<PathBuf, Option<SystemTime>> {
+        let dir_files = self
+            .dirs
+            .iter()
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file());
+        self.files
+            .iter()
+            .cloned()
+            .chain(dir_files)
+            .map(|path| {
+                let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+                (path, modified)
+            })
+            .collect()
+    }
+
+    /// Whether a file was created, modified or removed since the last call.
+    pub fn changed(&mut self) -> bool {
+        let snapshot = self.scan();
+        let changed = snapshot != self.snapshot;
+        self.snapshot = snapshot;
+        changed
+    }
+
+    /// Blocks until a file changes.
+    pub fn wait(&mut self) {
+        while !self.changed() {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::Watcher;
+
+    #[test]
+    fn detect_changes() {
+        let dir = env::temp_dir().join(format!("advent-2022-watch-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("day1-test.txt");
+        let mut watcher = Watcher::new(vec![input.clone()], vec![dir.clone()]);
+        assert!(!watcher.changed());
+        fs::write(&input, "1\n").unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+        fs::write(dir.join("day1-test-other.txt"), "2\n").unwrap();
+        assert!(watcher.changed());
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(watcher.changed());
+    }
+}
@@ -0,0 +1,51 @@
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils;
+use crate::utils::{Answer, Day, ParseError};
+
+fn read_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut result = vec![0];
+
+    for (i, line) in utils::lines(input).iter().enumerate() {
+        if line.is_empty() {
+            result.push(0);
+        } else {
+            let index = result.len() - 1;
+            result[index] += utils::parse_at::<u64>(line, 1).map_err(|e| e.at_line(i + 1))?;
+        }
+    }
+    result.sort_by(|a, b| b.cmp(a));
+    Ok(result)
+}
+pub struct Day1 {
+    sorted_elfs: Vec<u64>,
+}
+
+impl Day1 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        Ok(Box::new(Day1 {
+            sorted_elfs: read_input(input)?,
+        }))
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 1,
+    title: "Calorie Counting",
+    parts: [
+        "Calories carried by the elf carrying the most",
+        "Calories carried by the top three elves",
+    ],
+    params: &[],
+    build: |input, _| Day1::new(input),
+};
+
+impl Day for Day1 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        self.sorted_elfs[0].into()
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        self.sorted_elfs.iter().take(3).sum::<u64>().into()
+    }
+}
@@ -1,6 +1,8 @@
-use std::{path::Path, str::FromStr};
+use std::str::FromStr;
 
-use crate::utils::{self, Day};
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, Answer, Day, ParseError};
 
 #[derive(PartialEq, Debug, Eq, Hash, Clone, Copy)]
 pub enum Instruction {
@@ -9,13 +11,13 @@ pub enum Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "noop" => Ok(Self::Noop),
-            s if s.starts_with("addx") => Ok(Self::Addx(utils::split2::<String, i64>(s, " ").1)),
-            _ => Err(format!("Unknown instruction {}", s)),
+            s if s.starts_with("addx") => Ok(Self::Addx(utils::split2::<String, i64>(s, " ")?.1)),
+            _ => Err(ParseError::new("noop or addx", s).at_column(1)),
         }
     }
 }
@@ -25,9 +27,9 @@ pub struct Day10 {
 }
 
 impl Day10 {
-    pub fn new(filepath: &Path) -> Box<dyn Day<i64, String>> {
-        let instructions = utils::parse_lines(&utils::read_lines(filepath));
-        Box::new(Self { instructions })
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        let instructions = utils::parse_lines_with(&utils::lines(input), str::parse)?;
+        Ok(Box::new(Self { instructions }))
     }
 
     fn run_pipeline<F>(&self, at_cycle: &mut F)
@@ -48,27 +50,31 @@ impl Day10 {
     }
 }
 
-impl Day<i64, String> for Day10 {
-    fn solve_a(&self) -> i64 {
+pub const INFO: DayInfo = DayInfo {
+    day: 10,
+    title: "Cathode-Ray Tube",
+    parts: ["Sum of the signal strengths", "Image rendered on the CRT"],
+    params: &[],
+    build: |input, _| Day10::new(input),
+};
+
+impl Day for Day10 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
         let mut result = 0;
         self.run_pipeline(&mut |cycle, register| {
             if cycle == 20 || (cycle > 20 && (cycle - 20) % 40 == 0) {
                 result += (cycle as i64) * register;
             }
         });
-        result
+        result.into()
     }
 
-    fn solve_b(&self) -> String {
+    fn solve_b(&self, _progress: &Progress) -> Answer {
         let mut result = vec![];
         self.run_pipeline(&mut |cycle, register| {
             let in_range = (register - 1..=register + 1).contains(&((cycle as i64 - 1) % 40));
             result.push(if in_range { "#" } else { "." });
         });
-        result
-            .chunks(40)
-            .map(|s| s.join(""))
-            .collect::<Vec<_>>()
-            .join("\n")
+        Answer::lines(result.chunks(40).map(|s| s.join("")))
     }
 }
@@ -0,0 +1,246 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::params::{ParamSpec, Params};
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, get_cap, get_caps, Answer, Day, ParseError};
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    Old,
+    Constant(u64),
+}
+
+impl Value {
+    fn eval(&self, old: u64) -> u64 {
+        match self {
+            Value::Old => old,
+            Value::Constant(c) => *c,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Operation {
+    lhs: Value,
+    op: String,
+    rhs: Value,
+}
+
+impl Operation {
+    /// Works on `u128`, which holds the sum or product of any two levels.
+    fn eval(&self, old: u64) -> u128 {
+        let (lhs, rhs) = (self.lhs.eval(old) as u128, self.rhs.eval(old) as u128);
+        match self.op.as_str() {
+            "+" => lhs + rhs,
+            "*" => lhs * rhs,
+            _ => panic!("Unknown operatoroperation: {}", self.op),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    id: usize,
+    items: Vec<u64>,
+    operation: Operation,
+    divisble_by: u64,
+    monkey_true: usize,
+    monkey_false: usize,
+}
+
+#[derive(Debug)]
+pub struct Env {
+    monkeys: Vec<Monkey>,
+    divisor: u64,
+    common_denominator: u64,
+    items: HashMap<usize, Vec<u64>>,
+    inspections: HashMap<usize, u64>,
+}
+
+impl Env {
+    fn new(monkeys: Vec<Monkey>, divisor: u64) -> Env {
+        let common_denominator = monkeys.iter().map(|m| m.divisble_by).product::<u64>();
+        let items = HashMap::from_iter(monkeys.iter().map(|m| (m.id, m.items.clone())));
+        let inspections = HashMap::from_iter(monkeys.iter().map(|m| (m.id, 0)));
+        Env {
+            monkeys,
+            items,
+            inspections,
+            divisor,
+            common_denominator,
+        }
+    }
+
+    fn run_round(&mut self) {
+        for monkey in self.monkeys.iter() {
+            for item in self.items.get(&monkey.id).unwrap().clone() {
+                *self.inspections.get_mut(&monkey.id).unwrap() += 1;
+                let (worriness, monkey_id) = monkey.process_item(item, self.divisor);
+                let worriness = (worriness % self.common_denominator as u128) as u64;
+                self.items.get_mut(&monkey_id).unwrap().push(worriness);
+            }
+            self.items.get_mut(&monkey.id).unwrap().clear();
+        }
+    }
+
+    fn result(&self) -> u128 {
+        let mut inspections = self.inspections.values().collect::<Vec<_>>();
+        inspections.sort_by(|a, b| b.cmp(a));
+        *inspections[0] as u128 * *inspections[1] as u128
+    }
+}
+
+impl Monkey {
+    fn process_item(&self, item: u64, div: u64) -> (u128, usize) {
+        let worriness = self.operation.eval(item) / div as u128;
+        if worriness.is_multiple_of(self.divisble_by as u128) {
+            (worriness, self.monkey_true)
+        } else {
+            (worriness, self.monkey_false)
+        }
+    }
+}
+
+pub struct Day11 {
+    monkeys: Vec<Monkey>,
+    rounds_a: usize,
+    rounds_b: usize,
+    relief: u64,
+}
+
+fn parse_value(caps: &regex::Captures, i: usize) -> Result<Value, ParseError> {
+    if utils::get_cap_str(caps, i)? == "old" {
+        Ok(Value::Old)
+    } else {
+        Ok(Value::Constant(get_cap(caps, i)?))
+    }
+}
+
+fn parse_monkey(first_line: usize, lines: &[String]) -> Result<Monkey, ParseError> {
+    if lines.len() < 6 {
+        let found = format!("{} lines", lines.len());
+        return Err(ParseError::new("6 lines per monkey", found).at_line(first_line + lines.len()));
+    }
+    let at_line = |i: usize| move |e: ParseError| e.at_line(first_line + i);
+    let id_caps = get_caps(r"Monkey (\d+):", &lines[0]).map_err(at_line(0))?;
+    let items_caps = get_caps(r"Starting items: (.*)", &lines[1]).map_err(at_line(1))?;
+    let items_start = items_caps.get(1).unwrap().start();
+    let op_caps = get_caps(r"Operation: new = (old|\d+) ([*+]) (old|\d+)", &lines[2])
+        .map_err(at_line(2))?;
+    let div_caps = get_caps(r"Test: divisible by (\d+)", &lines[3]).map_err(at_line(3))?;
+    let divisble_by = utils::get_cap(&div_caps, 1).map_err(at_line(3))?;
+    if divisble_by == 0 {
+        let column = div_caps.get(1).unwrap().start() + 1;
+        let error = ParseError::new("a divisor above 0", "0").at_column(column);
+        return Err(at_line(3)(error));
+    }
+    let monkey_re = r"If (?:true|false): throw to monkey (\d+)";
+    let true_caps = get_caps(monkey_re, &lines[4]).map_err(at_line(4))?;
+    let false_caps = get_caps(monkey_re, &lines[5]).map_err(at_line(5))?;
+
+    Ok(Monkey {
+        id: get_cap(&id_caps, 1).map_err(at_line(0))?,
+        items: utils::split_list(&lines[1][items_start..], ", ")
+            .map_err(|e| e.shift_column(items_start))
+            .map_err(at_line(1))?,
+        operation: Operation {
+            lhs: parse_value(&op_caps, 1).map_err(at_line(2))?,
+            op: utils::get_cap(&op_caps, 2).map_err(at_line(2))?,
+            rhs: parse_value(&op_caps, 3).map_err(at_line(2))?,
+        },
+        divisble_by,
+        monkey_true: utils::get_cap(&true_caps, 1).map_err(at_line(4))?,
+        monkey_false: utils::get_cap(&false_caps, 1).map_err(at_line(5))?,
+    })
+}
+
+impl Day11 {
+    pub fn new(input: &str, params: &Params) -> Result<Box<dyn Day>, ParseError> {
+        let lines = utils::lines(input);
+        let monkeys = lines
+            .chunks(7)
+            .enumerate()
+            .map(|(i, chunk)| parse_monkey(i * 7 + 1, chunk))
+            .collect::<Result<Vec<_>, _>>()?;
+        if monkeys.len() < 2 {
+            let found = monkeys.len().to_string();
+            return Err(ParseError::new("at least two monkeys", found));
+        }
+        // Levels are kept modulo the product of the divisors, in 64 bits.
+        let mut divisors = monkeys.iter().map(|m| m.divisble_by);
+        if divisors.try_fold(1u64, |product, d| product.checked_mul(d)).is_none() {
+            let error = ParseError::new("divisors whose product fits in 64 bits", "more");
+            return Err(error);
+        }
+        let ids = monkeys.iter().map(|m| m.id).collect::<HashSet<_>>();
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for (offset, target) in [(4, monkey.monkey_true), (5, monkey.monkey_false)] {
+                if !ids.contains(&target) {
+                    let line = &lines[i * 7 + offset];
+                    let column = line.rfind(' ').map_or(1, |space| space + 2);
+                    return Err(ParseError::new("the id of a monkey", target.to_string())
+                        .at_column(column)
+                        .at_line(i * 7 + offset + 1));
+                }
+            }
+        }
+        Ok(Box::new(Day11 {
+            monkeys,
+            rounds_a: params.get("rounds_a"),
+            rounds_b: params.get("rounds_b"),
+            relief: params.get("relief"),
+        }))
+    }
+
+    fn solve(&self, iterations: usize, divisor: u64) -> u128 {
+        let mut env = Env::new(self.monkeys.clone(), divisor);
+        for _ in 0..iterations {
+            env.run_round();
+        }
+        env.result()
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 11,
+    title: "Monkey in the Middle",
+    parts: [
+        "Monkey business after a few rounds",
+        "Monkey business after many rounds without relief",
+    ],
+    params: &[
+        ParamSpec {
+            name: "rounds_a",
+            default: 20,
+            min: 0,
+            max: 1_000_000,
+            description: "Rounds in part A",
+        },
+        ParamSpec {
+            name: "rounds_b",
+            default: 10_000,
+            min: 0,
+            max: 1_000_000,
+            description: "Rounds in part B",
+        },
+        ParamSpec {
+            name: "relief",
+            default: 3,
+            min: 1,
+            max: 1_000_000,
+            description: "Divisor of the worry levels after each inspection in part A",
+        },
+    ],
+    build: Day11::new,
+};
+
+impl Day for Day11 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        Answer::Integer(self.solve(self.rounds_a, self.relief) as i128)
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        Answer::Integer(self.solve(self.rounds_b, 1) as i128)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::grid::{Grid, Point};
+use crate::pathfinding;
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils;
+use crate::utils::{Answer, Day, ParseError};
+
+pub struct Day12 {
+    grid: Grid<char>,
+    end: Point,
+}
+
+impl Day12 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        Self::from_grid(Grid::from(&utils::lines(input))?)
+    }
+
+    /// Solves the heightmap of `grid`.
+    pub fn from_grid(grid: Grid<char>) -> Result<Box<dyn Day>, ParseError> {
+        for c in ['S', 'E'] {
+            if !grid.values().any(|v| *v == c) {
+                return Err(ParseError::new(format!("a {} cell", c), "none"));
+            }
+        }
+        let end = grid.iter().find(|(_, c)| **c == 'E').unwrap().0;
+        Ok(Box::new(Self { grid, end }))
+    }
+}
+
+impl Day12 {
+    fn get_cell(&self, point: &Point) -> Option<char> {
+        match self.grid.get(point) {
+            Some('E') => Some('z'),
+            Some('S') => Some('a'),
+            Some(c) => Some(*c),
+            None => None,
+        }
+    }
+
+    /// The squares that can be climbed to from `point`, at most one higher.
+    fn get_valid_neighbors(&self, point: &Point) -> Vec<(Point, u64)> {
+        let current_val = self.get_cell(point).unwrap();
+        self.grid
+            .get_neighbors(point, false)
+            .into_iter()
+            .filter(|p| {
+                self.get_cell(p)
+                    .is_some_and(|next_val| next_val as u64 <= current_val as u64 + 1)
+            })
+            .map(|p| (p, 1))
+            .collect()
+    }
+
+    fn starts(&self, heights: &[char]) -> Vec<Point> {
+        let starts = self.grid.iter().filter(|(_, c)| heights.contains(c));
+        starts.map(|(p, _)| p).collect()
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 12,
+    title: "Hill Climbing Algorithm",
+    parts: [
+        "Fewest steps from the start",
+        "Fewest steps from any lowest square",
+    ],
+    params: &[],
+    build: |input, _| Day12::new(input),
+};
+
+impl Day for Day12 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        let graph = |p: &Point| self.get_valid_neighbors(p);
+        let search = pathfinding::astar(
+            &graph,
+            self.starts(&['S']),
+            |p| p.manhattan_distance(&self.end),
+            |p| *p == self.end,
+        );
+        search.goal_cost().map_or(Answer::NoSolution, Answer::from)
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        let graph = |p: &Point| self.get_valid_neighbors(p);
+        let search = pathfinding::bfs(&graph, self.starts(&['S', 'a']), |p| *p == self.end);
+        search.goal_cost().map_or(Answer::NoSolution, Answer::from)
+    }
+}
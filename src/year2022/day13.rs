@@ -0,0 +1,151 @@
+use std::str::FromStr;
+
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, Answer, Day, ParseError};
+
+#[derive(Debug, Clone, Eq)]
+enum Value {
+    Integer(u64),
+    List(Vec<Value>),
+}
+
+fn unexpected(expected: &str, s: &[char], i: usize) -> ParseError {
+    let found = s.get(i).map_or("end of line".to_owned(), |c| c.to_string());
+    ParseError::new(expected, found).at_column(i + 1)
+}
+
+/// Parses the value starting at index `start` and returns it along with
+/// the index right after it.
+fn parse_value(s: &[char], start: usize) -> Result<(Value, usize), ParseError> {
+    match s.get(start) {
+        Some('[') => {
+            let mut children = vec![];
+            let mut i = start + 1;
+            while s.get(i) != Some(&']') {
+                let (child, next) = parse_value(s, i)?;
+                children.push(child);
+                i = next;
+                match s.get(i) {
+                    Some(',') => i += 1,
+                    Some(']') => {}
+                    _ => return Err(unexpected("',' or ']'", s, i)),
+                }
+            }
+            Ok((Value::List(children), i + 1))
+        }
+        Some(c) if c.is_ascii_digit() => {
+            let mut value: u64 = 0;
+            let mut i = start;
+            while let Some(digit) = s.get(i).and_then(|c| c.to_digit(10)) {
+                let next = value.checked_mul(10).and_then(|v| v.checked_add(digit as u64));
+                let Some(next) = next else {
+                    let digits = s[start..].iter().take_while(|c| c.is_ascii_digit());
+                    let error = ParseError::new("an integer of 64 bits", digits.collect::<String>());
+                    return Err(error.at_column(start + 1));
+                };
+                value = next;
+                i += 1;
+            }
+            Ok((Value::Integer(value), i))
+        }
+        _ => Err(unexpected("'[' or a digit", s, start)),
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Integer(a), Value::Integer(b)) => a == b,
+            (Value::List(a), Value::List(b)) => a == b,
+            (v1 @ Value::List(_), v2 @ Value::Integer(_)) => v1 == &Value::List(vec![v2.clone()]),
+            (v1 @ Value::Integer(_), v2 @ Value::List(_)) => v2 == v1,
+        }
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Value::Integer(a), Value::Integer(b)) => a.cmp(b),
+            (Value::List(a), Value::List(b)) => a.cmp(b),
+            (v1 @ Value::List(_), v2 @ Value::Integer(_)) => v1.cmp(&Value::List(vec![v2.clone()])),
+            (v1 @ Value::Integer(_), v2 @ Value::List(_)) => Value::List(vec![v1.clone()]).cmp(v2),
+        }
+    }
+}
+
+impl FromStr for Value {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = s.chars().collect::<Vec<_>>();
+        let (value, end) = parse_value(&chars, 0)?;
+        if end != chars.len() {
+            return Err(unexpected("end of line", &chars, end));
+        }
+        Ok(value)
+    }
+}
+
+pub struct Day13 {
+    inputs: Vec<Value>,
+}
+
+impl Day13 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        let lines = utils::lines(input);
+        let inputs = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| !l.is_empty())
+            .map(|(i, v)| v.parse().map_err(|e: ParseError| e.at_line(i + 1)))
+            .collect::<Result<Vec<Value>, _>>()?;
+        if inputs.len() % 2 == 1 {
+            let error = ParseError::new("the second packet of the pair", "end of file");
+            return Err(error.at_line(lines.len() + 1));
+        }
+        Ok(Box::new(Day13 { inputs }))
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 13,
+    title: "Distress Signal",
+    parts: [
+        "Sum of the indices of the ordered pairs",
+        "Decoder key of the divider packets",
+    ],
+    params: &[],
+    build: |input, _| Day13::new(input),
+};
+
+impl Day for Day13 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        self.inputs
+            .chunks(2)
+            .enumerate()
+            .map(|(i, v)| if v[0] <= v[1] { i as u64 + 1 } else { 0 })
+            .sum::<u64>()
+            .into()
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        let mut inputs_with_packets = self.inputs.clone();
+        let first_packet: Value = "[[2]]".parse().unwrap();
+        let second_packet: Value = "[[6]]".parse().unwrap();
+        inputs_with_packets.push(first_packet.clone());
+        inputs_with_packets.push(second_packet.clone());
+        inputs_with_packets.sort();
+        let get_index = |p| inputs_with_packets.iter().position(|v| v == p).unwrap() as u64 + 1;
+        let index_first = get_index(&first_packet);
+        let index_second = get_index(&second_packet);
+        (index_first * index_second).into()
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    grid::{Grid as BaseGrid, Point},
+    progress::Progress,
+    registry::DayInfo,
+    utils::{self, Answer, Day, ParseError},
+};
+
+/// The cave, unbounded: rock is `#` and sand at rest `.`.
+type Grid = BaseGrid<char>;
+
+pub struct Day14 {
+    grid: Grid,
+}
+
+struct Env {
+    grid: Grid,
+    bottom: i64,
+    left: i64,
+    right: i64,
+}
+
+impl Env {
+    fn new(grid: Grid) -> Self {
+        let bottom = grid.iter().map(|(p, _)| p.y).max().unwrap();
+        let left = grid.iter().map(|(p, _)| p.x).min().unwrap();
+        let right = grid.iter().map(|(p, _)| p.x).max().unwrap();
+        Self {
+            grid,
+            bottom,
+            left,
+            right,
+        }
+    }
+
+    fn get_sand_next_position(&self, sand: Point) -> Option<Point> {
+        let one_below = Point::new(sand.x, sand.y + 1);
+        if !self.grid.contains(&one_below) {
+            return Some(one_below);
+        }
+
+        let one_diagonal_left = Point::new(sand.x - 1, sand.y + 1);
+        if !self.grid.contains(&one_diagonal_left) {
+            return Some(one_diagonal_left);
+        }
+
+        let one_diagonal_right = Point::new(sand.x + 1, sand.y + 1);
+        if !self.grid.contains(&one_diagonal_right) {
+            return Some(one_diagonal_right);
+        }
+
+        None
+    }
+
+    fn add_sand(&mut self) -> bool {
+        let mut sand = Point::new(500, 0);
+        while sand.y <= self.bottom {
+            if let Some(new_sand) = self.get_sand_next_position(sand) {
+                sand = new_sand;
+            } else {
+                self.grid.insert(sand, '.');
+                return sand != Point::new(500, 0);
+            }
+        }
+        false
+    }
+
+    fn fill_with_sand(&mut self) {
+        while self.add_sand() {}
+    }
+
+    fn count_sand(&self) -> u64 {
+        self.grid.values().filter(|c| **c == '.').count() as u64
+    }
+
+    fn fill_bottom(&mut self) {
+        // Sand spreads at most as far sideways from the source as it falls.
+        let reach = self.bottom + 2;
+        for x in (500 - reach).min(self.left)..=(500 + reach).max(self.right) {
+            let point = Point::new(x, self.bottom + 2);
+            self.grid.insert(point, '#');
+        }
+        self.bottom += 2;
+    }
+}
+
+fn parse_line(line: &str) -> Result<Vec<Point>, ParseError> {
+    let mut column = 1;
+    let mut points = vec![];
+    for s in line.split(" -> ") {
+        let (x, y) = utils::split2(s, ",").map_err(|e| e.shift_column(column - 1))?;
+        points.push(Point::new(x, y));
+        column += s.len() + " -> ".len();
+    }
+    Ok(points)
+}
+
+fn generate_grid(lines: &[String]) -> Result<Grid, ParseError> {
+    let mut grid = Grid::new(0, 0, true);
+    let lines: Vec<_> = utils::parse_lines_with(lines, parse_line)?;
+    for line in lines.iter() {
+        let mut from = line[0];
+        // A path of a single point is still a rock.
+        grid.insert(from, '#');
+        for to in line.iter().skip(1) {
+            for x in from.x.min(to.x)..=from.x.max(to.x) {
+                for y in from.y.min(to.y)..=from.y.max(to.y) {
+                    grid.insert(Point::new(x, y), '#');
+                }
+            }
+            from = *to;
+        }
+    }
+    Ok(grid)
+}
+
+impl Day14 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        let grid = generate_grid(&utils::lines(input))?;
+        if grid.is_empty() {
+            return Err(ParseError::new("at least one rock path", "none"));
+        }
+
+        Ok(Box::new(Day14 { grid }))
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 14,
+    title: "Regolith Reservoir",
+    parts: [
+        "Sand at rest before it flows into the abyss",
+        "Sand at rest once the source is blocked",
+    ],
+    params: &[],
+    build: |input, _| Day14::new(input),
+};
+
+impl Day for Day14 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        let mut env = Env::new(self.grid.clone());
+        env.fill_with_sand();
+        env.count_sand().into()
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        let mut env = Env::new(self.grid.clone());
+        env.fill_bottom();
+        env.fill_with_sand();
+        env.count_sand().into()
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+
+use crate::{
+    grid::Point,
+    params::{ParamSpec, Params},
+    progress::Progress,
+    registry::DayInfo,
+    utils::{self, get_cap, Answer, Day, ParseError},
+};
+
+pub struct Day15 {
+    items: Vec<(Point, Point)>,
+    row: i64,
+    bound: i64,
+}
+
+impl Day15 {
+    pub fn new(input: &str, params: &Params) -> Result<Box<dyn Day>, ParseError> {
+        let items = utils::parse_lines_with(&utils::lines(input), parse_line)?;
+        if items.is_empty() {
+            return Err(ParseError::new("at least one sensor", "none"));
+        }
+        Ok(Box::new(Self {
+            items,
+            row: params.get("row"),
+            bound: params.get("bound"),
+        }))
+    }
+
+    fn beacons_in_range(&self, start_x: i64, end_x: i64, height: i64) -> i64 {
+        self.items
+            .iter()
+            .map(|(_, beacon)| beacon)
+            .filter(|beacon| beacon.y == height && beacon.x >= start_x && beacon.x <= end_x)
+            .collect::<HashSet<_>>()
+            .len() as i64
+    }
+
+    fn compute_ranges(&self, height: i64, max: Option<i64>) -> Vec<(i64, i64)> {
+        let ranges = self
+            .items
+            .iter()
+            .filter_map(|(sensor, beacon)| compute_range_at(sensor, beacon, height));
+        if let Some(max) = max {
+            ranges
+                .map(|(start, end)| (start.max(0), end.min(max)))
+                .collect()
+        } else {
+            ranges.collect()
+        }
+    }
+
+    /// Tuning frequency of the only position in the bounds that no sensor
+    /// covers, `None` if the sensors cover them all.
+    fn find_distress_beacon(&self, progress: &Progress) -> Option<i64> {
+        let max = self.bound;
+        let beacons = self
+            .items
+            .iter()
+            .map(|(_, beacon)| beacon)
+            .collect::<HashSet<_>>();
+        for height in 0..=max {
+            progress.report(height as u64, max as u64 + 1, None);
+            let ranges = self.compute_ranges(height, Some(max));
+            // The gaps between the ranges, and before and after them.
+            let mut next_x = 0;
+            for (start, end) in dedup_ranges(&ranges)
+                .into_iter()
+                .chain([(max + 1, max + 1)])
+            {
+                for x in next_x..start {
+                    let candidate = Point::new(x, height);
+                    if !beacons.contains(&candidate) {
+                        return Some(candidate.x * 4_000_000 + candidate.y);
+                    }
+                }
+                next_x = next_x.max(end + 1);
+            }
+        }
+        None
+    }
+}
+
+fn parse_line(line: &str) -> Result<(Point, Point), ParseError> {
+    let caps = utils::get_caps(
+        r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)",
+        line,
+    )?;
+    let sensor = Point::new(get_cap(&caps, 1)?, get_cap(&caps, 2)?);
+    let beacon = Point::new(get_cap(&caps, 3)?, get_cap(&caps, 4)?);
+    Ok((sensor, beacon))
+}
+
+fn compute_range_at(sensor: &Point, beacon: &Point, height: i64) -> Option<(i64, i64)> {
+    let distance = sensor.manhattan_distance(beacon) as i64;
+    let vertical_distance = (sensor.y - height).abs();
+    let diff = distance - vertical_distance;
+    if diff < 0 {
+        return None;
+    }
+    Some((sensor.x - diff, sensor.x + diff))
+}
+
+fn dedup_ranges(ranges: &[(i64, i64)]) -> Vec<(i64, i64)> {
+    let mut ranges = ranges.to_owned();
+    ranges.sort_by_key(|r| r.0);
+    let mut deduped = Vec::new();
+    if ranges.is_empty() {
+        return deduped;
+    }
+    let mut current_start = ranges[0].0;
+    let mut current_end = ranges[0].1;
+    for (start, end) in ranges.iter().skip(1) {
+        if *start <= current_end {
+            current_end = current_end.max(*end);
+        } else {
+            deduped.push((current_start, current_end));
+            current_start = *start;
+            current_end = *end;
+        }
+    }
+    deduped.push((current_start, current_end));
+    deduped
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 15,
+    title: "Beacon Exclusion Zone",
+    parts: [
+        "Positions where a beacon cannot be on the row",
+        "Tuning frequency of the distress beacon",
+    ],
+    params: &[
+        ParamSpec {
+            name: "row",
+            default: 2_000_000,
+            min: -1_000_000_000_000,
+            max: 1_000_000_000_000,
+            description: "Row where part A counts the positions",
+        },
+        ParamSpec {
+            name: "bound",
+            default: 4_000_000,
+            min: 0,
+            max: 10_000_000,
+            description: "Largest coordinate of the distress beacon",
+        },
+    ],
+    build: Day15::new,
+};
+
+impl Day for Day15 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        let ranges = self.compute_ranges(self.row, None);
+        let dedupped_ranges = dedup_ranges(&ranges);
+        dedupped_ranges
+            .iter()
+            .map(|(start, end)| *end - *start + 1 - self.beacons_in_range(*start, *end, self.row))
+            .sum::<i64>()
+            .into()
+    }
+
+    fn solve_b(&self, progress: &Progress) -> Answer {
+        self.find_distress_beacon(progress)
+            .map_or(Answer::NoSolution, Answer::from)
+    }
+}
@@ -1,11 +1,9 @@
-use std::{
-    collections::{HashMap, HashSet},
-    path::Path,
-};
-
-use regex::Regex;
+use std::collections::{HashMap, HashSet};
 
-use crate::utils::{get_cap, read_lines, Day};
+use crate::params::{ParamSpec, Params};
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{get_cap, get_caps, lines, parse_lines_with, Answer, Day, ParseError};
 
 #[derive(Debug)]
 struct Valve {
@@ -18,6 +16,8 @@ pub struct Day16 {
     valves: HashMap<String, Valve>,
     non_zero_valves: u64,
     sorted_valve_sizes: Vec<u64>,
+    minutes_a: u64,
+    minutes_b: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -45,19 +45,29 @@ impl State {
 }
 
 impl Day16 {
-    pub fn new(filepath: &Path) -> Box<dyn Day> {
-        let valves: HashMap<_, _> = read_lines(filepath).iter().map(|l| parse_line(l)).collect();
+    pub fn new(input: &str, params: &Params) -> Result<Box<dyn Day>, ParseError> {
+        let valves: HashMap<_, _> = parse_lines_with(&lines(input), parse_line)?
+            .into_iter()
+            .collect();
+        let unknown_valve = std::iter::once("AA")
+            .chain(valves.values().flat_map(|v| v.tunnels.iter().map(String::as_str)))
+            .find(|name| !valves.contains_key(*name));
+        if let Some(name) = unknown_valve {
+            return Err(ParseError::new("a known valve", name));
+        }
         let non_zero_valves = valves.values().filter(|v| v.flow_rate > 0).count() as u64;
         let mut sorted_valve_sizes = valves.values().map(|v| v.flow_rate).collect::<Vec<_>>();
         sorted_valve_sizes.sort_by(|a, b| b.cmp(a));
-        Box::new(Day16 {
+        Ok(Box::new(Day16 {
             valves,
             non_zero_valves,
             sorted_valve_sizes,
-        })
+            minutes_a: params.get("minutes_a"),
+            minutes_b: params.get("minutes_b"),
+        }))
     }
 
-    fn solve(&self, players: usize, initial_time_left: u64) -> u64 {
+    fn solve(&self, players: usize, initial_time_left: u64, progress: &Progress) -> u64 {
         let initial_position = Position {
             current: "AA".to_owned(),
             previous: "".to_owned(),
@@ -70,6 +80,8 @@ impl Day16 {
         let mut states = vec![initial_state];
 
         for time_left in (1..initial_time_left).rev() {
+            let minute = initial_time_left - time_left;
+            progress.report(minute, initial_time_left - 1, Some(states.len()));
             states = self.compute_next_states(&states, 0, time_left);
             if players == 2 {
                 states = self.compute_next_states(&states, 1, time_left);
@@ -151,28 +163,54 @@ impl Day16 {
     }
 }
 
-fn parse_line(line: &str) -> (String, Valve) {
-    let re =
-        Regex::new(r"Valve ([A-Z]+) has flow rate=(\d+); tunnels? leads? to valves? ([A-Z ,]+)")
-            .unwrap();
-    let caps = re.captures(line).unwrap();
+fn parse_line(line: &str) -> Result<(String, Valve), ParseError> {
+    let caps = get_caps(
+        r"Valve ([A-Z]+) has flow rate=(\d+); tunnels? leads? to valves? ([A-Z ,]+)",
+        line,
+    )?;
     let valve = Valve {
-        name: get_cap(&caps, 1),
-        flow_rate: get_cap(&caps, 2),
-        tunnels: get_cap::<String>(&caps, 3)
+        name: get_cap(&caps, 1)?,
+        flow_rate: get_cap(&caps, 2)?,
+        tunnels: get_cap::<String>(&caps, 3)?
             .split(", ")
             .map(|s| s.to_string())
             .collect(),
     };
-    (valve.name.clone(), valve)
+    Ok((valve.name.clone(), valve))
 }
 
+pub const INFO: DayInfo = DayInfo {
+    day: 16,
+    title: "Proboscidea Volcanium",
+    parts: [
+        "Most pressure released alone",
+        "Most pressure released with an elephant",
+    ],
+    params: &[
+        ParamSpec {
+            name: "minutes_a",
+            default: 30,
+            min: 0,
+            max: 1_000,
+            description: "Minutes before the eruption in part A",
+        },
+        ParamSpec {
+            name: "minutes_b",
+            default: 26,
+            min: 0,
+            max: 1_000,
+            description: "Minutes left after teaching the elephant in part B",
+        },
+    ],
+    build: Day16::new,
+};
+
 impl Day for Day16 {
-    fn solve_a(&self) -> u64 {
-        self.solve(1, 30)
+    fn solve_a(&self, progress: &Progress) -> Answer {
+        self.solve(1, self.minutes_a, progress).into()
     }
 
-    fn solve_b(&self) -> u64 {
-        self.solve(2, 26)
+    fn solve_b(&self, progress: &Progress) -> Answer {
+        self.solve(2, self.minutes_b, progress).into()
     }
 }
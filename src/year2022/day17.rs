@@ -1,8 +1,9 @@
-use std::path::Path;
-
 use crate::{
     grid::{Grid, Point},
-    utils::{self, Day},
+    params::{ParamSpec, Params},
+    progress::Progress,
+    registry::DayInfo,
+    utils::{self, Answer, Day, ParseError},
 };
 
 #[rustfmt::skip]
@@ -35,7 +36,7 @@ impl Rock {
 
     fn update_map(&self, map: &mut Grid<char>) {
         for p in self.pattern {
-            map.cells.insert(self.position + *p, '#');
+            map.insert(self.position + *p, '#');
         }
     }
 
@@ -64,7 +65,7 @@ impl Rock {
     fn is_colliding(&self, map: &Grid<char>) -> bool {
         self.pattern.iter().any(|p| {
             let p = self.position + *p;
-            map.cells.contains_key(&p) || p.x < 0 || p.x > 6 || p.y < 0
+            map.contains(&p) || p.x < 0 || p.x > 6 || p.y < 0
         })
     }
 }
@@ -115,34 +116,80 @@ impl Env {
 
 pub struct Day17 {
     jet_pattern: Vec<char>,
+    rocks_a: usize,
+    rocks_b: u64,
 }
 
 impl Day17 {
-    pub fn new(filepath: &Path) -> Box<dyn Day> {
-        let jet_pattern = utils::read_lines(filepath)[0].chars().collect();
-        Box::new(Day17 { jet_pattern })
+    pub fn new(input: &str, params: &Params) -> Result<Box<dyn Day>, ParseError> {
+        let lines = utils::lines(input);
+        let line = lines
+            .first()
+            .ok_or_else(|| ParseError::new("a jet pattern", "end of file").at_line(1))?;
+        if line.is_empty() {
+            return Err(ParseError::new("a jet pattern", "an empty line").at_line(1));
+        }
+        if let Some(i) = line.chars().position(|c| c != '<' && c != '>') {
+            let found = line.chars().nth(i).unwrap();
+            return Err(ParseError::new("'<' or '>'", found).at_line(1).at_column(i + 1));
+        }
+        let jet_pattern = line.chars().collect();
+        Ok(Box::new(Day17 {
+            jet_pattern,
+            rocks_a: params.get("rocks_a"),
+            rocks_b: params.get("rocks_b"),
+        }))
     }
 }
 
+pub const INFO: DayInfo = DayInfo {
+    day: 17,
+    title: "Pyroclastic Flow",
+    parts: [
+        "Tower height after a few rocks",
+        "Tower height after a huge number of rocks",
+    ],
+    params: &[
+        ParamSpec {
+            name: "rocks_a",
+            default: 2022,
+            min: 0,
+            max: 1_000_000,
+            description: "Rocks dropped in part A",
+        },
+        ParamSpec {
+            name: "rocks_b",
+            default: 1_000_000_000_000,
+            min: 0,
+            max: 1_000_000_000_000_000_000,
+            description: "Rocks dropped in part B",
+        },
+    ],
+    build: Day17::new,
+};
+
 impl Day for Day17 {
-    fn solve_a(&self) -> u64 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
         let mut env = Env::new(self.jet_pattern.clone());
-        for i in 0..2022 {
+        for i in 0..self.rocks_a {
             env.drop_rock(i % 5);
         }
-        env.map.height
+        env.map.height.into()
     }
 
-    fn solve_b(&self) -> u64 {
+    fn solve_b(&self, _progress: &Progress) -> Answer {
         let mut env = Env::new(self.jet_pattern.clone());
         let mut i = 0;
-        while env.laps.len() < 10 {
+        while env.laps.len() < 10 && env.rocks_thrown < self.rocks_b {
             env.drop_rock(i % 5);
             i += 1;
         }
+        if env.rocks_thrown == self.rocks_b {
+            return env.map.height.into();
+        }
         let rocks_per_lap = env.laps[9].0 - env.laps[4].0;
         let height_per_lap = env.laps[9].1 - env.laps[4].1;
-        let iterations_left = 1_000_000_000_000_u64 - env.rocks_thrown;
+        let iterations_left = self.rocks_b - env.rocks_thrown;
         let laps_performed = iterations_left / rocks_per_lap;
         let laps_left = iterations_left % rocks_per_lap;
         for _ in 0..laps_left {
@@ -150,6 +197,6 @@ impl Day for Day17 {
             i += 1;
         }
 
-        env.map.height + laps_performed * height_per_lap
+        (env.map.height + laps_performed * height_per_lap).into()
     }
 }
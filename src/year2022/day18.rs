@@ -1,6 +1,9 @@
-use std::{collections::HashSet, path::Path};
+use std::collections::HashSet;
 
-use crate::utils::{self, Day};
+use crate::pathfinding;
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, Answer, Day, ParseError};
 
 type Bound3D = ((i32, i32), (i32, i32), (i32, i32));
 
@@ -43,9 +46,9 @@ pub struct Day18 {
     bounds: ((i32, i32), (i32, i32), (i32, i32)),
 }
 
-fn parse_line(line: &str) -> Point3D {
-    let (x, y, z) = utils::split3(line, ",");
-    Point3D { x, y, z }
+fn parse_line(line: &str) -> Result<Point3D, ParseError> {
+    let (x, y, z) = utils::split3(line, ",")?;
+    Ok(Point3D { x, y, z })
 }
 
 fn get_min(points: &HashSet<Point3D>, f: fn(&Point3D) -> i32) -> i32 {
@@ -57,37 +60,41 @@ fn get_max(points: &HashSet<Point3D>, f: fn(&Point3D) -> i32) -> i32 {
 }
 
 impl Day18 {
-    pub fn new(filepath: &Path) -> Box<dyn Day> {
-        let points = utils::read_lines(filepath)
-            .iter()
-            .map(|line| parse_line(line))
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        let points: HashSet<_> = utils::parse_lines_with(&utils::lines(input), parse_line)?
+            .into_iter()
             .collect();
+        if points.is_empty() {
+            return Err(ParseError::new("at least one cube", "none"));
+        }
         let (min_x, max_x) = (get_min(&points, |p| p.x), get_max(&points, |p| p.x));
         let (min_y, max_y) = (get_min(&points, |p| p.y), get_max(&points, |p| p.y));
         let (min_z, max_z) = (get_min(&points, |p| p.z), get_max(&points, |p| p.z));
-        Box::new(Self {
+        Ok(Box::new(Self {
             points,
             bounds: ((min_x, max_x), (min_y, max_y), (min_z, max_z)),
-        })
+        }))
     }
 
-    fn is_trapped(&self, point: &Point3D) -> bool {
-        let mut open_set = vec![*point];
-        let mut seen = HashSet::new();
-        while !open_set.is_empty() {
-            let current = open_set.pop().unwrap();
-            seen.insert(current);
-            if !current.is_within_bounds(&self.bounds) {
-                return false;
-            }
-            for neighbor in current.get_neighbors() {
-                if !seen.contains(&neighbor) && !self.points.contains(&neighbor) {
-                    open_set.push(neighbor);
-                }
-            }
-        }
-
-        true
+    /// The air around the droplet, in a box one cube larger than its bounds,
+    /// reached by flooding the box from one of its corners.
+    fn exterior(&self) -> HashSet<Point3D> {
+        let ((min_x, max_x), (min_y, max_y), (min_z, max_z)) = self.bounds;
+        let bounds = (
+            (min_x - 1, max_x + 1),
+            (min_y - 1, max_y + 1),
+            (min_z - 1, max_z + 1),
+        );
+        let graph = |p: &Point3D| {
+            let neighbors = p.get_neighbors().into_iter();
+            neighbors
+                .filter(|n| n.is_within_bounds(&bounds) && !self.points.contains(n))
+                .map(|n| (n, 1))
+                .collect()
+        };
+        let corner = Point3D::new(min_x - 1, min_y - 1, min_z - 1);
+        let search = pathfinding::bfs(&graph, [corner], |_| false);
+        search.reached().copied().collect()
     }
 
     fn solve<P>(&self, predicate: P) -> u64
@@ -101,12 +108,24 @@ impl Day18 {
     }
 }
 
+pub const INFO: DayInfo = DayInfo {
+    day: 18,
+    title: "Boiling Boulders",
+    parts: [
+        "Surface area of the droplet",
+        "Exterior surface area of the droplet",
+    ],
+    params: &[],
+    build: |input, _| Day18::new(input),
+};
+
 impl Day for Day18 {
-    fn solve_a(&self) -> u64 {
-        self.solve(|p| !self.points.contains(p))
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        self.solve(|p| !self.points.contains(p)).into()
     }
 
-    fn solve_b(&self) -> u64 {
-        self.solve(|p| !self.points.contains(p) && !self.is_trapped(p))
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        let exterior = self.exterior();
+        self.solve(|p| exterior.contains(p)).into()
     }
 }
@@ -1,6 +1,9 @@
-use std::{collections::HashSet, path::Path};
+use std::collections::HashSet;
 
-use crate::utils::{captures, get_cap, read_lines, Day};
+use crate::params::{ParamSpec, Params};
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{captures, get_cap, lines, parse_lines_with, Answer, Day, ParseError};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Materials {
@@ -112,14 +115,23 @@ struct Blueprint {
 }
 
 impl Blueprint {
-    fn compute_best_score(&self, n: usize) -> u64 {
+    /// Reports the minutes to `progress`, as the `steps_done` to `steps_done + n`
+    /// steps out of `steps`.
+    fn compute_best_score(
+        &self,
+        n: usize,
+        progress: &Progress,
+        steps_done: u64,
+        steps: u64,
+    ) -> u64 {
         let mut states = HashSet::from([State::new()]);
 
-        for _ in 0..n {
+        for minute in 0..n {
+            progress.report(steps_done + minute as u64, steps, Some(states.len()));
             let mut new_states = HashSet::new();
             for mut state in states.into_iter() {
                 state.advance();
-                new_states.extend(state.get_next_states(self).into_iter());
+                new_states.extend(state.get_next_states(self));
             }
             states = new_states;
         }
@@ -138,43 +150,105 @@ impl Blueprint {
 
 pub struct Day19 {
     blueprints: Vec<Blueprint>,
+    minutes_a: usize,
+    minutes_b: usize,
+    blueprints_b: usize,
 }
 
 impl Day19 {
-    pub fn new(filepath: &Path) -> Box<dyn Day> {
-        let blueprints = read_lines(filepath)
+    pub fn new(input: &str, params: &Params) -> Result<Box<dyn Day>, ParseError> {
+        let blueprints = parse_lines_with(&lines(input), parse_line)?;
+        Ok(Box::new(Day19 {
+            blueprints,
+            minutes_a: params.get("minutes_a"),
+            minutes_b: params.get("minutes_b"),
+            blueprints_b: params.get("blueprints_b"),
+        }))
+    }
+
+    /// Best scores of the first `count` blueprints.
+    fn best_scores(&self, count: usize, minutes: usize, progress: &Progress) -> Vec<u64> {
+        let steps = (count * minutes) as u64;
+        (0..count)
+            .map(|i| {
+                let steps_done = (i * minutes) as u64;
+                let blueprint = &self.blueprints[i];
+                let score = blueprint.compute_best_score(minutes, progress, steps_done, steps);
+                progress.trace(format_args!("blueprint {}: {} geodes", i + 1, score));
+                score
+            })
+            .collect()
+    }
+
+    fn quality_levels(&self, progress: &Progress) -> u64 {
+        self.best_scores(self.blueprints.len(), self.minutes_a, progress)
             .iter()
-            .map(|line| parse_line(line))
-            .collect();
-        Box::new(Day19 { blueprints })
+            .zip(1..)
+            .map(|(score, id)| score * id)
+            .sum()
     }
-}
 
-fn parse_line(line: &str) -> Blueprint {
-    let obsidian = captures(r"obsidian robot costs (\d+) ore and (\d+) clay", line);
-    let geode = captures(r"geode robot costs (\d+) ore and (\d+) obsidian", line);
-    Blueprint {
-        ore: get_cap(&captures(r"ore robot costs (\d+)", line), 1),
-        clay: get_cap(&captures(r"clay robot costs (\d+)", line), 1),
-        obsidian: (get_cap(&obsidian, 1), get_cap(&obsidian, 2)),
-        geode: (get_cap(&geode, 1), get_cap(&geode, 2)),
+    /// `None` when there are fewer blueprints than the elephants left.
+    fn uneaten_geodes(&self, progress: &Progress) -> Option<u64> {
+        if self.blueprints_b > self.blueprints.len() {
+            return None;
+        }
+        let scores = self.best_scores(self.blueprints_b, self.minutes_b, progress);
+        Some(scores.iter().product())
     }
 }
 
+fn parse_line(line: &str) -> Result<Blueprint, ParseError> {
+    let obsidian = captures(r"obsidian robot costs (\d+) ore and (\d+) clay", line)?;
+    let geode = captures(r"geode robot costs (\d+) ore and (\d+) obsidian", line)?;
+    Ok(Blueprint {
+        ore: get_cap(&captures(r"ore robot costs (\d+)", line)?, 1)?,
+        clay: get_cap(&captures(r"clay robot costs (\d+)", line)?, 1)?,
+        obsidian: (get_cap(&obsidian, 1)?, get_cap(&obsidian, 2)?),
+        geode: (get_cap(&geode, 1)?, get_cap(&geode, 2)?),
+    })
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 19,
+    title: "Not Enough Minerals",
+    parts: [
+        "Sum of the quality levels",
+        "Product of the geodes of the first blueprints, with more time",
+    ],
+    params: &[
+        ParamSpec {
+            name: "minutes_a",
+            default: 24,
+            min: 0,
+            max: 32,
+            description: "Minutes to open geodes in part A",
+        },
+        ParamSpec {
+            name: "minutes_b",
+            default: 32,
+            min: 0,
+            max: 32,
+            description: "Minutes to open geodes in part B",
+        },
+        ParamSpec {
+            name: "blueprints_b",
+            default: 3,
+            min: 0,
+            max: 1_000,
+            description: "Blueprints left uneaten by the elephants in part B",
+        },
+    ],
+    build: Day19::new,
+};
+
 impl Day for Day19 {
-    fn solve_a(&self) -> u64 {
-        self.blueprints
-            .iter()
-            .enumerate()
-            .map(|(i, b)| (i as u64 + 1) * b.compute_best_score(24))
-            .sum()
+    fn solve_a(&self, progress: &Progress) -> Answer {
+        self.quality_levels(progress).into()
     }
 
-    fn solve_b(&self) -> u64 {
-        self.blueprints
-            .iter()
-            .take(3)
-            .map(|b| b.compute_best_score(32))
-            .product()
+    fn solve_b(&self, progress: &Progress) -> Answer {
+        self.uneaten_geodes(progress)
+            .map_or(Answer::NoSolution, Answer::from)
     }
 }
@@ -0,0 +1,88 @@
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, Answer, Day, ParseError};
+
+fn score(hand: &char) -> u64 {
+    match hand {
+        'X' => 1,
+        'Y' => 2,
+        'Z' => 3,
+        _ => panic!("Invalid hand: {}", hand),
+    }
+}
+
+fn score_round(player: &char, other: &char) -> u64 {
+    match (player, other) {
+        ('X', 'A') | ('Y', 'B') | ('Z', 'C') => 3,
+        ('X', 'C') | ('Y', 'A') | ('Z', 'B') => 6,
+        _ => 0,
+    }
+}
+
+fn get_hand(player: &char, other: &char) -> char {
+    match (player, other) {
+        ('X', 'A') | ('Y', 'C') | ('Z', 'B') => 'Z',
+        ('X', 'B') | ('Y', 'A') | ('Z', 'C') => 'X',
+        _ => 'Y',
+    }
+}
+
+fn parse_line(line: &str) -> Result<(char, char), ParseError> {
+    let (other, player) = utils::split2(line, " ")?;
+    if !('A'..='C').contains(&other) {
+        return Err(ParseError::new("A, B or C", other).at_column(1));
+    }
+    if !('X'..='Z').contains(&player) {
+        return Err(ParseError::new("X, Y or Z", player).at_column(3));
+    }
+    Ok((other, player))
+}
+
+fn read_input(input: &str) -> Result<Vec<(char, char)>, ParseError> {
+    utils::parse_lines_with(&utils::lines(input), parse_line)
+}
+
+fn compute_score(input: &[(char, char)]) -> u64 {
+    input
+        .iter()
+        .map(|(a, b)| score_round(b, a) + score(b))
+        .sum()
+}
+
+pub struct Day2 {
+    input: Vec<(char, char)>,
+}
+
+impl Day2 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        Ok(Box::new(Day2 {
+            input: read_input(input)?,
+        }))
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 2,
+    title: "Rock Paper Scissors",
+    parts: [
+        "Score when the second column is our shape",
+        "Score when the second column is the outcome",
+    ],
+    params: &[],
+    build: |input, _| Day2::new(input),
+};
+
+impl Day for Day2 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        compute_score(&self.input).into()
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        let input: Vec<(char, char)> = self
+            .input
+            .iter()
+            .map(|(a, b)| (*a, get_hand(b, a)))
+            .collect();
+        compute_score(&input).into()
+    }
+}
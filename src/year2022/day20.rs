@@ -0,0 +1,112 @@
+use crate::params::{ParamSpec, Params};
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, Answer, Day, ParseError};
+
+pub struct Day20 {
+    input: Vec<i64>,
+    key: i64,
+}
+
+#[derive(Debug)]
+struct TrackedList {
+    list: Vec<(usize, i128)>,
+}
+
+impl std::fmt::Display for TrackedList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = String::new();
+        self.list
+            .iter()
+            .for_each(|(_, v)| s.push_str(&format!("{} ", v)));
+        write!(f, "{}", s)
+    }
+}
+
+impl TrackedList {
+    fn new(numbers: Vec<i128>) -> Self {
+        let list = numbers.into_iter().enumerate().collect();
+        Self { list }
+    }
+
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn nth_after(&self, i: usize, n: usize) -> i128 {
+        let index = (i + n % self.len()) % self.len();
+        self.list[index].1
+    }
+
+    fn move_elem(&mut self, i: usize) {
+        let (index, (_, value)) = self.list.iter().enumerate().find(|v| v.1 .0 == i).unwrap();
+        let v = *value;
+        self.list.remove(index);
+        let target = (index + ((v.rem_euclid(self.len() as i128)) as usize)) % self.len();
+        self.list.insert(target, (i, v));
+    }
+
+    fn move_all(&mut self) {
+        for i in 0..self.list.len() {
+            self.move_elem(i);
+        }
+    }
+}
+
+impl Day20 {
+    pub fn new(input: &str, params: &Params) -> Result<Box<dyn Day>, ParseError> {
+        let input: Vec<i64> = utils::parse_lines(&utils::lines(input))?;
+        // A number moves among the others, so it needs at least one.
+        if input.len() < 2 {
+            let found = input.len().to_string();
+            return Err(ParseError::new("at least two numbers", found));
+        }
+        if input.iter().filter(|v| **v == 0).count() != 1 {
+            return Err(ParseError::new("exactly one 0", "none or several"));
+        }
+        Ok(Box::new(Day20 {
+            input,
+            key: params.get("key"),
+        }))
+    }
+}
+
+/// Works on `i128`, which holds any number multiplied by any key.
+fn solve(input: Vec<i128>, moves: usize) -> i128 {
+    let mut list = TrackedList::new(input);
+    for _ in 0..moves {
+        list.move_all();
+    }
+    let index_0 = list.list.iter().enumerate().find(|v| v.1.1 == 0).unwrap().0;
+    [1000, 2000, 3000].iter().map(|n| list.nth_after(index_0, *n)).sum()
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 20,
+    title: "Grove Positioning System",
+    parts: [
+        "Sum of the grove coordinates",
+        "Sum of the grove coordinates with the decryption key",
+    ],
+    params: &[ParamSpec {
+        name: "key",
+        default: 811_589_153,
+        min: -1_000_000_000_000,
+        max: 1_000_000_000_000,
+        description: "Decryption key of part B",
+    }],
+    build: Day20::new,
+};
+
+impl Day for Day20 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        let input = self.input.iter().map(|v| *v as i128).collect();
+        Answer::Integer(solve(input, 1))
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        let key = self.key as i128;
+        let input = self.input.iter().map(|v| *v as i128 * key).collect();
+        Answer::Integer(solve(input, 10))
+    }
+}
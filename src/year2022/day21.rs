@@ -0,0 +1,199 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, captures, get_cap_str, Answer, Day, ParseError};
+
+#[derive(Clone)]
+enum Expr {
+    Bin(String, Box<Expr>, Box<Expr>),
+    Variable(String),
+    Constant(u64),
+}
+
+impl Expr {
+    fn eval(&self, env: &Environment) -> i64 {
+        match self {
+            Expr::Constant(n) => *n as i64,
+            Expr::Variable(s) => env.variables.get(s).unwrap().eval(env),
+            Expr::Bin(op, lhs, rhs) => {
+                let (lhs_v, rhs_v) = (lhs.eval(env), rhs.eval(env));
+                match op.as_str() {
+                    "+" => lhs_v + rhs_v,
+                    "-" => lhs_v - rhs_v,
+                    "*" => lhs_v * rhs_v,
+                    "/" => lhs_v / rhs_v,
+                    _ => panic!("Unknown operator: {}", op),
+                }
+            }
+        }
+    }
+
+    /// Whether every division of the expression has no remainder.
+    fn is_exact(&self, env: &Environment) -> bool {
+        match self {
+            Expr::Constant(_) => true,
+            Expr::Variable(s) => env.variables.get(s).unwrap().is_exact(env),
+            Expr::Bin(op, lhs, rhs) => {
+                lhs.is_exact(env)
+                    && rhs.is_exact(env)
+                    && (op != "/" || lhs.eval(env) % rhs.eval(env) == 0)
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Environment {
+    variables: HashMap<String, Expr>,
+}
+
+pub struct Day21 {
+    env: Environment,
+}
+
+fn parse_line(line: &str) -> Result<(String, Expr), ParseError> {
+    let (name, expr) = line
+        .split_once(": ")
+        .ok_or_else(|| ParseError::new("\": \"", "end of line").at_column(line.len() + 1))?;
+    if let Ok(n) = expr.parse() {
+        Ok((name.to_string(), Expr::Constant(n)))
+    } else {
+        let caps = captures(r"^([a-z]+) ([\+\*/-]) ([a-z]+)$", expr)
+            .map_err(|e| e.shift_column(name.len() + 2))?;
+        Ok((
+            name.to_string(),
+            Expr::Bin(
+                get_cap_str(&caps, 2)?,
+                Box::new(Expr::Variable(get_cap_str(&caps, 1)?)),
+                Box::new(Expr::Variable(get_cap_str(&caps, 3)?)),
+            ),
+        ))
+    }
+}
+
+/// A monkey waiting, through the monkeys it waits on, for itself, among
+/// `name` and the monkeys it waits on. `done` holds the monkeys already
+/// known to wait for none.
+fn find_cycle<'a>(
+    variables: &'a HashMap<String, Expr>,
+    name: &'a str,
+    waiting: &mut HashSet<&'a str>,
+    done: &mut HashSet<&'a str>,
+) -> Option<&'a str> {
+    if done.contains(name) {
+        return None;
+    }
+    if !waiting.insert(name) {
+        return Some(name);
+    }
+    if let Some(Expr::Bin(_, lhs, rhs)) = variables.get(name) {
+        for operand in [lhs, rhs] {
+            if let Expr::Variable(other) = operand.as_ref() {
+                let cycle = find_cycle(variables, other, waiting, done);
+                if cycle.is_some() {
+                    return cycle;
+                }
+            }
+        }
+    }
+    waiting.remove(name);
+    done.insert(name);
+    None
+}
+
+impl Day21 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        let lines = utils::lines(input);
+        let monkeys = utils::parse_lines_with(&lines, parse_line)?;
+        let variables: HashMap<_, _> = monkeys.iter().cloned().collect();
+        if !matches!(variables.get("root"), Some(Expr::Bin(..))) {
+            return Err(ParseError::new("a root operation", "none"));
+        }
+        if !variables.contains_key("humn") {
+            return Err(ParseError::new("a monkey named humn", "none"));
+        }
+        let (mut waiting, mut done) = (HashSet::new(), HashSet::new());
+        for (i, (name, expr)) in monkeys.iter().enumerate() {
+            let Expr::Bin(_, lhs, rhs) = expr else {
+                continue;
+            };
+            for (j, operand) in [lhs, rhs].into_iter().enumerate() {
+                let Expr::Variable(other) = operand.as_ref() else {
+                    continue;
+                };
+                if !variables.contains_key(other) {
+                    // The operands follow "name: " and end the line.
+                    let column = match j {
+                        0 => name.len() + 3,
+                        _ => lines[i].len() - other.len() + 1,
+                    };
+                    let error = ParseError::new("the name of a monkey", other);
+                    return Err(error.at_column(column).at_line(i + 1));
+                }
+            }
+            if let Some(cycle) = find_cycle(&variables, name, &mut waiting, &mut done) {
+                let line = monkeys.iter().position(|(other, _)| other == cycle).unwrap();
+                let error = ParseError::new("a monkey not waiting for itself", cycle);
+                return Err(error.at_line(line + 1));
+            }
+        }
+        let env = Environment { variables };
+        Ok(Box::new(Self { env }))
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 21,
+    title: "Monkey Math",
+    parts: [
+        "Number yelled by root",
+        "Number to yell to pass root's equality test",
+    ],
+    params: &[],
+    build: |input, _| Day21::new(input),
+};
+
+impl Day for Day21 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        self.env.variables.get("root").unwrap().eval(&self.env).into()
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        let mut env = self.env.clone();
+        let (lhs, rhs) = match self.env.variables.get("root").unwrap() {
+            Expr::Bin(_, lhs, rhs) => (lhs, rhs),
+            _ => panic!("No root"),
+        };
+        // How far apart the two sides of root's test are when yelling `humn`,
+        // which only goes one way as `humn` grows, and whether their
+        // divisions are exact.
+        let mut yell = |humn| {
+            env.variables.insert("humn".to_string(), Expr::Constant(humn));
+            let exact = lhs.is_exact(&env) && rhs.is_exact(&env);
+            (lhs.eval(&env) - rhs.eval(&env), exact)
+        };
+        let mut difference = |humn| yell(humn).0;
+        let (mut left, mut right) = (0, u64::MAX / 1_000_000); // lower to avoid overflows
+        let increasing = difference(left) < difference(right);
+        // The smallest number reaching the other side, found by bisection.
+        while left < right {
+            let i = left + (right - left) / 2;
+            let d = difference(i);
+            if (increasing && d >= 0) || (!increasing && d <= 0) {
+                right = i;
+            } else {
+                left = i + 1;
+            }
+        }
+        // Divisions round down, so the following numbers can pass the test
+        // too, but only one divides exactly.
+        (left..)
+            .map_while(|humn| match yell(humn) {
+                (0, exact) => Some((humn, exact)),
+                _ => None,
+            })
+            .find(|(_, exact)| *exact)
+            .map_or(Answer::NoSolution, |(humn, _)| (humn as i64).into())
+    }
+}
@@ -0,0 +1,386 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    grid::{Grid as GenericGrid, Point, Topology},
+    progress::Progress,
+    registry::DayInfo,
+    utils::{self, Answer, Day, ParseError},
+};
+
+type Grid = GenericGrid<char>;
+
+const RIGHT: Point = Point { x: 1, y: 0 };
+const DOWN: Point = Point { x: 0, y: 1 };
+const LEFT: Point = Point { x: -1, y: 0 };
+const UP: Point = Point { x: 0, y: -1 };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Turn(Direction),
+    Advance(u64),
+}
+
+#[derive(Debug)]
+struct Player {
+    position: Point,
+    direction: Point,
+}
+
+/// A direction in space, or a position in a cube centered on the origin.
+type Vector = [i64; 3];
+
+/// The sum of the vectors, each one multiplied by its factor.
+fn combine(terms: &[(Vector, i64)]) -> Vector {
+    let mut sum = [0; 3];
+    for (vector, factor) in terms {
+        for i in 0..3 {
+            sum[i] += vector[i] * factor;
+        }
+    }
+    sum
+}
+
+fn dot(a: Vector, b: Vector) -> i64 {
+    (0..3).map(|i| a[i] * b[i]).sum()
+}
+
+/// A face of the cube: its top left corner on the map, and where its
+/// normal and the right and down directions of the map point in space.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    origin: Point,
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+
+impl Face {
+    /// The direction in space of a direction on the map.
+    fn direction(&self, direction: Point) -> Vector {
+        combine(&[(self.right, direction.x), (self.down, direction.y)])
+    }
+}
+
+/// The map folded into a cube whose faces are `size` cells wide.
+#[derive(Debug)]
+struct Cube {
+    size: i64,
+    faces: Vec<Face>,
+    /// Where walking off the map from a position in a direction leads: the
+    /// position, its tile and the direction on the next face.
+    edges: HashMap<(Point, Point), (Point, char, Point)>,
+}
+
+impl Cube {
+    /// Folds the map along the edges of its faces, `None` if it is not the
+    /// net of a cube.
+    fn fold(grid: &Grid) -> Result<Option<Cube>, ParseError> {
+        let size = ((grid.len() / 6) as f64).sqrt() as i64;
+        if size == 0 || 6 * size * size != grid.len() as i64 {
+            return Ok(None);
+        }
+        let Some((first, _)) = grid.iter().next() else {
+            return Ok(None);
+        };
+        let mut faces = vec![Face {
+            origin: Point::new(first.x / size * size, first.y / size * size),
+            normal: [0, 0, -1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        }];
+        let mut i = 0;
+        while i < faces.len() {
+            let face = faces[i];
+            for direction in [RIGHT, DOWN, LEFT, UP] {
+                let origin = Point::new(
+                    face.origin.x + direction.x * size,
+                    face.origin.y + direction.y * size,
+                );
+                if !grid.contains(&origin) || faces.iter().any(|f| f.origin == origin) {
+                    continue;
+                }
+                // The neighbor folds along the shared edge: it faces where the
+                // edge leads, and going further away from the edge on it goes
+                // against the normal of this face.
+                let away = combine(&[(face.normal, -1)]);
+                let (right, down) = match direction.x {
+                    0 => (face.right, combine(&[(away, direction.y)])),
+                    x => (combine(&[(away, x)]), face.down),
+                };
+                faces.push(Face {
+                    origin,
+                    normal: face.direction(direction),
+                    right,
+                    down,
+                });
+            }
+            i += 1;
+        }
+        let mut cube = Cube {
+            size,
+            faces,
+            edges: HashMap::new(),
+        };
+        let complete = (0..size).all(|y| {
+            (0..size).all(|x| {
+                let cells = cube.faces.iter().map(|f| f.origin + Point::new(x, y));
+                cells.filter(|p| grid.contains(p)).count() == 6
+            })
+        });
+        let normals = cube.faces.iter().map(|f| f.normal);
+        let distinct = normals.collect::<HashSet<_>>().len() == 6;
+        if cube.faces.len() != 6 || !complete || !distinct {
+            return Ok(None);
+        }
+        for (position, _) in grid.iter() {
+            for direction in [RIGHT, DOWN, LEFT, UP] {
+                if grid.contains(&(position + direction)) {
+                    continue;
+                }
+                let (next, next_direction) = cube.wrap(position, direction)?;
+                let Some(c) = grid.get(&next) else {
+                    let error = ParseError::new("a tile across the edge", "none");
+                    return Err(error.at_column(next.x as usize + 1).at_line(next.y as usize + 1));
+                };
+                cube.edges.insert((position, direction), (next, *c, next_direction));
+            }
+        }
+        Ok(Some(cube))
+    }
+
+    fn face_of(&self, point: Point) -> Result<&Face, ParseError> {
+        let size = self.size;
+        self.faces
+            .iter()
+            .find(|f| {
+                (f.origin.x..f.origin.x + size).contains(&point.x)
+                    && (f.origin.y..f.origin.y + size).contains(&point.y)
+            })
+            .ok_or_else(|| ParseError::new("a cell on a face of the cube", point.to_string()))
+    }
+
+    /// Where walking off the face of `position` in `direction` leads: the
+    /// position and the direction on the next face.
+    fn wrap(&self, position: Point, direction: Point) -> Result<(Point, Point), ParseError> {
+        let size = self.size;
+        let face = self.face_of(position)?;
+        let towards = face.direction(direction);
+        let Some(next) = self.faces.iter().find(|f| f.normal == towards) else {
+            let found = format!("none next to the face at {}", face.origin);
+            return Err(ParseError::new("a face of the cube", found));
+        };
+        // Cell centers in a cube going from -size to size.
+        let x = 2 * (position.x - face.origin.x) + 1 - size;
+        let y = 2 * (position.y - face.origin.y) + 1 - size;
+        // The cell of the next face along the edge, one step away from it.
+        let center = combine(&[
+            (face.right, x),
+            (face.down, y),
+            (towards, 1),
+            (face.normal, size - 1),
+        ]);
+        let x = (dot(next.right, center) + size - 1) / 2;
+        let y = (dot(next.down, center) + size - 1) / 2;
+        let away = combine(&[(face.normal, -1)]);
+        let Some(direction) = [RIGHT, DOWN, LEFT, UP]
+            .into_iter()
+            .find(|d| next.direction(*d) == away)
+        else {
+            let found = format!("none leaving the face at {}", next.origin);
+            return Err(ParseError::new("a direction on the cube", found));
+        };
+        Ok((next.origin + Point::new(x, y), direction))
+    }
+}
+
+#[derive(Debug)]
+struct Env<'a> {
+    grid: &'a Grid,
+    player: Player,
+    /// `None` when the map is not the net of a cube.
+    cube: Option<&'a Cube>,
+}
+
+impl<'a> Env<'a> {
+    fn new(grid: &'a Grid, start: Point, cube: Option<&'a Cube>) -> Env<'a> {
+        let player = Player::new(start, RIGHT);
+        Env { grid, player, cube }
+    }
+
+    fn get_next_state(&self, position: Point, direction: Point) -> (Point, char, Point) {
+        let mut next_point = position;
+        loop {
+            next_point = self.grid.step(next_point, direction).unwrap();
+            if let Some(c) = self.grid.get(&next_point) {
+                break (next_point, *c, direction);
+            }
+        }
+    }
+
+    fn get_next_state_3d(&self, position: Point, direction: Point) -> (Point, char, Point) {
+        let next_point = position + direction;
+        match (self.grid.get(&next_point), self.cube) {
+            (Some(c), _) => (next_point, *c, direction),
+            (None, Some(cube)) => cube.edges[&(position, direction)],
+            (None, None) => unreachable!("the 3D moves need a cube"),
+        }
+    }
+
+    fn compute_next_state(&self, is_3d: bool) -> Option<Player> {
+        let next_state_f = if is_3d {
+            Env::get_next_state_3d
+        } else {
+            Env::get_next_state
+        };
+        match next_state_f(self, self.player.position, self.player.direction) {
+            (p, '.', d) => Some(Player::new(p, d)),
+            (_, '#', _) => None,
+            (_, c, _) => panic!("Unexpected character: {}", c),
+        }
+    }
+
+    fn execute_instructions(&mut self, instructions: &[Instruction], is_3d: bool) {
+        for instr in instructions.iter() {
+            self.execute_instruction(instr, is_3d);
+        }
+    }
+
+    fn execute_instruction(&mut self, instruction: &Instruction, is_3d: bool) {
+        match instruction {
+            Instruction::Turn(dir) => self.player.turn(*dir),
+            Instruction::Advance(dist) => {
+                for _ in 0..*dist {
+                    match self.compute_next_state(is_3d) {
+                        Some(player) => self.player = player,
+                        None => break,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Player {
+    fn new(position: Point, direction: Point) -> Player {
+        Player { position, direction }
+    }
+
+    fn turn(&mut self, direction: Direction) {
+        match direction {
+            Direction::Left => self.direction = Point::new(self.direction.y, -self.direction.x),
+            Direction::Right => self.direction = Point::new(-self.direction.y, self.direction.x),
+        }
+    }
+
+    fn compute_score(&self) -> u64 {
+        // 0 for right (>), 1 for down (v), 2 for left (<), and 3 for up (^)
+        let direction_score = match self.direction {
+            RIGHT => 0,
+            DOWN => 1,
+            LEFT => 2,
+            UP => 3,
+            _ => panic!("Unexpected direction: {:?}", self.direction),
+        };
+        (1000 * (self.position.y + 1) + 4 * (self.position.x + 1) + direction_score) as u64
+    }
+}
+
+fn parse_instructions(line: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions = vec![];
+    let mut current_num = 0;
+    for (i, c) in line.chars().enumerate() {
+        match c {
+            'L' | 'R' => {
+                instructions.push(Instruction::Advance(current_num));
+                current_num = 0;
+                let dir = match c {
+                    'L' => Direction::Left,
+                    'R' => Direction::Right,
+                    _ => unreachable!(),
+                };
+                instructions.push(Instruction::Turn(dir));
+            }
+            '0'..='9' => {
+                let digit = c as u64 - '0' as u64;
+                let next = current_num.checked_mul(10).and_then(|n| n.checked_add(digit));
+                let Some(next) = next else {
+                    let error = ParseError::new("a distance of 64 bits", c);
+                    return Err(error.at_column(i + 1));
+                };
+                current_num = next;
+            }
+            _ => return Err(ParseError::new("a digit, 'L' or 'R'", c).at_column(i + 1)),
+        }
+    }
+    instructions.push(Instruction::Advance(current_num));
+    Ok(instructions)
+}
+
+pub struct Day22 {
+    grid: Grid,
+    start: Point,
+    /// `None` when the map is not the net of a cube.
+    cube: Option<Cube>,
+    instructions: Vec<Instruction>,
+}
+
+impl Day22 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        let lines = utils::lines(input);
+        if lines.len() < 3 {
+            let found = format!("{} lines", lines.len());
+            return Err(ParseError::new("a map followed by instructions", found));
+        }
+        let (grid_lines, rest) = lines.split_at(lines.len() - 2);
+        let instructions =
+            parse_instructions(&rest[1]).map_err(|e| e.at_line(lines.len()))?;
+        let mut grid = Grid::from_with(grid_lines, |_, c| matches!(c, '.' | '#').then_some(c));
+        grid.empty_cell = ' ';
+        grid.topology = Topology::Torus;
+        let Some(x) = grid_lines[0].chars().position(|c| c == '.') else {
+            return Err(ParseError::new("an open tile", grid_lines[0].clone()).at_line(1));
+        };
+        let start = Point::new(x as i64, 0);
+        let cube = Cube::fold(&grid)?;
+        Ok(Box::new(Day22 {
+            grid,
+            start,
+            cube,
+            instructions,
+        }))
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 22,
+    title: "Monkey Map",
+    parts: [
+        "Final password on the flat map",
+        "Final password on the cube",
+    ],
+    params: &[],
+    build: |input, _| Day22::new(input),
+};
+
+impl Day for Day22 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        let mut env = Env::new(&self.grid, self.start, None);
+        env.execute_instructions(&self.instructions, false);
+        env.player.compute_score().into()
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        if self.cube.is_none() {
+            return Answer::NoSolution;
+        }
+        let mut env = Env::new(&self.grid, self.start, self.cube.as_ref());
+        env.execute_instructions(&self.instructions, true);
+        env.player.compute_score().into()
+    }
+}
@@ -1,17 +1,18 @@
-use std::{
-    collections::{HashMap, HashSet},
-    path::Path,
-};
+use std::collections::{HashMap, HashSet};
 
 use crate::{
     grid::{get_neighbors, Grid as BaseGrid, Point},
-    utils::{self, Day},
+    params::{ParamSpec, Params},
+    progress::Progress,
+    registry::DayInfo,
+    utils::{self, Answer, Day, ParseError},
 };
 
 type Grid = BaseGrid<char>;
 
 pub struct Day23 {
     elves: HashSet<Point>,
+    rounds: usize,
 }
 
 struct Env {
@@ -119,30 +120,50 @@ impl Env {
 }
 
 impl Day23 {
-    pub fn new(input: &Path) -> Box<dyn Day> {
-        let grid = Grid::from(&utils::read_lines(input));
-        let elves = grid
-            .cells
+    pub fn new(input: &str, params: &Params) -> Result<Box<dyn Day>, ParseError> {
+        let elves = Grid::sparse_from_with(&utils::lines(input), |_, c| (c == '#').then_some(c))
             .iter()
-            .filter(|(_, c)| **c == '#')
-            .map(|(p, _)| *p)
-            .collect();
-        Box::new(Self { elves })
+            .map(|(p, _)| p)
+            .collect::<HashSet<_>>();
+        if elves.is_empty() {
+            return Err(ParseError::new("at least one elf", "none"));
+        }
+        Ok(Box::new(Self {
+            elves,
+            rounds: params.get("rounds"),
+        }))
     }
 }
 
+pub const INFO: DayInfo = DayInfo {
+    day: 23,
+    title: "Unstable Diffusion",
+    parts: [
+        "Empty ground tiles after a few rounds",
+        "First round where no elf moves",
+    ],
+    params: &[ParamSpec {
+        name: "rounds",
+        default: 10,
+        min: 0,
+        max: 10_000,
+        description: "Rounds before counting the empty ground in part A",
+    }],
+    build: Day23::new,
+};
+
 impl Day for Day23 {
-    fn solve_a(&self) -> u64 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
         let mut env = Env::new(self.elves.clone());
-        for _ in 0..10 {
+        for _ in 0..self.rounds {
             env.run_round();
         }
-        env.get_score()
+        env.get_score().into()
     }
 
-    fn solve_b(&self) -> u64 {
+    fn solve_b(&self, _progress: &Progress) -> Answer {
         let mut env = Env::new(self.elves.clone());
-        let mut i = 0;
+        let mut i: u64 = 0;
         loop {
             i += 1;
             let elves = env.elves.clone();
@@ -151,6 +172,6 @@ impl Day for Day23 {
                 break;
             }
         }
-        i
+        i.into()
     }
 }
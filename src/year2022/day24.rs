@@ -0,0 +1,203 @@
+use crate::{
+    grid::{Grid as BaseGrid, Point, Topology},
+    pathfinding,
+    progress::Progress,
+    registry::DayInfo,
+    utils::{self, Answer, Day, ParseError},
+};
+
+type Grid = BaseGrid<char>;
+
+pub struct Day24 {
+    grid: Grid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Direction {
+    fn from(c: char) -> Option<Self> {
+        match c {
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            _ => None,
+        }
+    }
+}
+
+impl Direction {
+    fn offset(self) -> Point {
+        match self {
+            Direction::Left => Point::new(-1, 0),
+            Direction::Right => Point::new(1, 0),
+            Direction::Up => Point::new(0, -1),
+            Direction::Down => Point::new(0, 1),
+        }
+    }
+}
+
+/// A position in the valley, with the minute modulo the period of the
+/// blizzards: states are then finite, so searches end when the goal cannot
+/// be reached.
+type State = (Point, u64);
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+struct Env {
+    origin: Point,
+    end: Point,
+    /// The number of minutes after which the blizzards are back where they
+    /// started.
+    period: u64,
+    /// The starting positions of the blizzards inside the valley, without
+    /// its walls: blizzards leaving it by an edge come back by the opposite
+    /// one.
+    valley: BaseGrid<Direction>,
+}
+
+impl Env {
+    fn new(grid: &Grid) -> Self {
+        let height = grid.height - 2;
+        let width = grid.width - 2;
+        let mut valley = match grid.is_dense() {
+            true => BaseGrid::dense(width, height, true),
+            false => BaseGrid::new(width, height, true),
+        };
+        valley.topology = Topology::Torus;
+        for (p, c) in grid.iter() {
+            if let Some(d) = Direction::from(*c) {
+                valley.insert(Point::new(p.x - 1, p.y - 1), d);
+            }
+        }
+        let origin = Point::new(0, -1);
+        let end = Point::new(width as i64 - 1, height as i64);
+        let period = width / gcd(width, height) * height;
+        Self {
+            origin,
+            end,
+            period,
+            valley,
+        }
+    }
+
+    /// Whether a blizzard is at `point` after `minute` minutes, i.e. whether
+    /// one started where it would have come from.
+    fn has_blizzard(&self, point: Point, minute: u64) -> bool {
+        let minute = minute as i64;
+        [
+            Direction::Left,
+            Direction::Right,
+            Direction::Up,
+            Direction::Down,
+        ]
+        .iter()
+        .any(|d| {
+            let offset = d.offset();
+            let back = Point::new(-offset.x * minute, -offset.y * minute);
+            let start = self.valley.step(point, back).unwrap();
+            self.valley.get(&start) == Some(d)
+        })
+    }
+
+    fn moves(&self, (p, minute): &State, from: Point, to: Point) -> Vec<(State, u64)> {
+        let diffs = [(0, 0), (1, 0), (0, 1), (-1, 0), (0, -1)];
+        diffs
+            .iter()
+            .map(|diff| Point::new(p.x + diff.0, p.y + diff.1))
+            .filter(|&next| {
+                next == from
+                    || next == to
+                    || (self.valley.in_bounds(&next) && !self.has_blizzard(next, minute + 1))
+            })
+            .map(|next| ((next, (minute + 1) % self.period), 1))
+            .collect()
+    }
+
+    /// The minute at which `to` is reached, leaving `from` at `minute`,
+    /// `None` if the blizzards always block the way.
+    fn find_shortest_path(&self, from: Point, to: Point, minute: u64) -> Option<u64> {
+        let graph = |state: &State| self.moves(state, from, to);
+        let start = (from, minute % self.period);
+        let search = pathfinding::bfs(&graph, [start], |(p, _)| *p == to);
+        Some(minute + search.goal_cost()?)
+    }
+}
+
+impl Day24 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        Self::from_grid(Grid::from(&utils::lines(input))?)
+    }
+
+    /// Solves the valley of `grid`, walls included. The blizzards are kept
+    /// in the same storage as `grid`.
+    pub fn from_grid(grid: Grid) -> Result<Box<dyn Day>, ParseError> {
+        if grid.width < 3 || grid.height < 3 {
+            let found = format!("{}x{}", grid.width, grid.height);
+            return Err(ParseError::new("a valley of at least 3x3", found));
+        }
+        // Blizzards wrap around inside the walls, so none may stand on them.
+        let (width, height) = (grid.width as i64, grid.height as i64);
+        for y in 0..height {
+            let (walls, allowed) = if y == 0 || y == height - 1 {
+                ((0..width).collect(), "#.")
+            } else {
+                (vec![0, width - 1], "#")
+            };
+            let wrong = walls.into_iter().find_map(|x| {
+                let c = grid.get(&Point::new(x, y));
+                // The first and last rows can end early.
+                let open = c.is_none() && allowed.contains('.');
+                match c {
+                    Some(c) if allowed.contains(*c) => None,
+                    None if open => None,
+                    c => Some((x, c.map_or("end of line".to_owned(), |c| c.to_string()))),
+                }
+            });
+            if let Some((x, found)) = wrong {
+                let error = ParseError::new(format!("one of {:?}", allowed), found);
+                return Err(error.at_column(x as usize + 1).at_line(y as usize + 1));
+            }
+        }
+        Ok(Box::new(Day24 { grid }))
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 24,
+    title: "Blizzard Basin",
+    parts: [
+        "Fewest minutes to reach the goal",
+        "Fewest minutes to reach the goal, go back and reach it again",
+    ],
+    params: &[],
+    build: |input, _| Day24::new(input),
+};
+
+impl Day for Day24 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        let env = Env::new(&self.grid);
+        let there = env.find_shortest_path(env.origin, env.end, 0);
+        there.map_or(Answer::NoSolution, Answer::from)
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        let env = Env::new(&self.grid);
+        let there = env.find_shortest_path(env.origin, env.end, 0);
+        let back = there.and_then(|there| env.find_shortest_path(env.end, env.origin, there));
+        let again = back.and_then(|back| env.find_shortest_path(env.origin, env.end, back));
+        again.map_or(Answer::NoSolution, Answer::from)
+    }
+}
@@ -1,6 +1,6 @@
-use std::path::Path;
-
-use crate::utils::{self, Day};
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, Answer, Day, ParseError};
 
 pub struct Day25 {
     numbers: Vec<String>,
@@ -28,7 +28,7 @@ fn decimal_to_snafu(mut number: u64) -> String {
     while number > 0 {
         let n = (number % 5) + leftover;
         match n % 5 {
-            r@(0 | 1 | 2) => result.push_str(format!("{}", r).as_str()),
+            r @ 0..=2 => result.push_str(format!("{}", r).as_str()),
             3 => result.push('='),
             4 => result.push('-'),
             r => panic!("Invalid number: {}", r),
@@ -44,24 +44,44 @@ fn decimal_to_snafu(mut number: u64) -> String {
 }
 
 impl Day25 {
-    pub fn new(filepath: &Path) -> Box<dyn Day<String, u64>> {
-        let numbers = utils::read_lines(filepath);
-        Box::new(Self { numbers })
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        let numbers = utils::lines(input);
+        utils::parse_lines_with(&numbers, |line| {
+            match line.chars().position(|c| !"=-012".contains(c)) {
+                Some(i) => {
+                    let found = line.chars().nth(i).unwrap();
+                    Err(ParseError::new("a SNAFU digit", found).at_column(i + 1))
+                }
+                None => Ok(()),
+            }
+        })?;
+        Ok(Box::new(Self { numbers }))
     }
 }
 
-impl Day<String, u64> for Day25 {
-    fn solve_a(&self) -> String {
+pub const INFO: DayInfo = DayInfo {
+    day: 25,
+    title: "Full of Hot Air",
+    parts: [
+        "SNAFU number to enter in the console",
+        "No puzzle, the last star is free",
+    ],
+    params: &[],
+    build: |input, _| Day25::new(input),
+};
+
+impl Day for Day25 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
         let sum = self
             .numbers
             .iter()
             .map(|n| snafu_to_decimal(n))
             .sum::<i64>();
-        decimal_to_snafu(sum as u64)
+        decimal_to_snafu(sum as u64).into()
     }
 
-    fn solve_b(&self) -> u64 {
-        0
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        Answer::NotApplicable
     }
 }
 
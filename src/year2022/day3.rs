@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, Answer, Day, ParseError};
+
+pub struct Day3 {
+    bags: Vec<String>,
+}
+
+impl Day3 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        let bags = utils::lines(input);
+        utils::parse_lines_with(&bags, |line| {
+            match line.chars().position(|c| !c.is_ascii_alphabetic()) {
+                Some(i) => {
+                    let found = line.chars().nth(i).unwrap();
+                    Err(ParseError::new("a letter", found).at_column(i + 1))
+                }
+                None if line.len() % 2 == 1 => {
+                    let found = format!("{} items", line.len());
+                    Err(ParseError::new("an even number of items", found))
+                }
+                None => Ok(()),
+            }
+        })?;
+        if !bags.len().is_multiple_of(3) {
+            let found = format!("{} rucksacks", bags.len());
+            let error = ParseError::new("groups of 3 rucksacks", found);
+            return Err(error.at_line(bags.len() + 1));
+        }
+        Ok(Box::new(Day3 { bags }))
+    }
+}
+
+fn intersect(s1: &str, s2: &str) -> String {
+    let s1_set: HashSet<char> = HashSet::from_iter(s1.chars());
+    s1_set.intersection(&HashSet::from_iter(s2.chars())).collect()
+}
+
+fn get_value(c: char) -> u64 {
+    if c.is_ascii_lowercase() {
+        c as u64 - 'a' as u64 + 1
+    } else {
+        c as u64 - 'A' as u64 + 27
+    }
+}
+
+fn compute_result(s: &str) -> u64 {
+    s.chars().map(get_value).sum()
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 3,
+    title: "Rucksack Reorganization",
+    parts: [
+        "Priorities of the items in both compartments",
+        "Priorities of the badges of each group",
+    ],
+    params: &[],
+    build: |input, _| Day3::new(input),
+};
+
+impl Day for Day3 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        self.bags
+            .iter()
+            .map(|line| {
+                let (a, b) = line.split_at(line.len() / 2);
+                compute_result(&intersect(a, b))
+            })
+            .sum::<u64>()
+            .into()
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        self.bags
+            .chunks(3)
+            .map(|chunk| compute_result(&intersect(&intersect(&chunk[0], &chunk[1]), &chunk[2])))
+            .sum::<u64>()
+            .into()
+    }
+}
@@ -0,0 +1,70 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, Answer, Day, ParseError};
+
+struct U64RangeInclusive(RangeInclusive<u64>);
+
+impl U64RangeInclusive {
+    fn includes(&self, other: &U64RangeInclusive) -> bool {
+        self.0.start() <= other.0.start() && self.0.end() >= other.0.end()
+    }
+
+    fn overlaps(&self, other: &U64RangeInclusive) -> bool {
+        self.0.start() <= other.0.end() && self.0.end() >= other.0.start()
+    }
+}
+
+impl FromStr for U64RangeInclusive {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = utils::split2::<u64, u64>(s, "-")?;
+
+        Ok(U64RangeInclusive(x..=y))
+    }
+}
+
+pub struct Day4 {
+    tasks: Vec<(U64RangeInclusive, U64RangeInclusive)>,
+}
+
+impl Day4 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        let lines = utils::lines(input);
+        let tasks = utils::parse_lines_with(&lines, |l| utils::split2(l, ","))?;
+        Ok(Box::new(Day4 { tasks }))
+    }
+
+    fn solve<P>(&self, mut predicate: P) -> u64
+    where
+        P: FnMut(&U64RangeInclusive, &U64RangeInclusive) -> bool,
+    {
+        self.tasks
+            .iter()
+            .filter(|(r1, r2)| predicate(r1, r2) || predicate(r2, r1))
+            .count() as u64
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 4,
+    title: "Camp Cleanup",
+    parts: [
+        "Pairs where one range contains the other",
+        "Pairs with overlapping ranges",
+    ],
+    params: &[],
+    build: |input, _| Day4::new(input),
+};
+
+impl Day for Day4 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        self.solve(U64RangeInclusive::includes).into()
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        self.solve(U64RangeInclusive::overlaps).into()
+    }
+}
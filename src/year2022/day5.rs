@@ -0,0 +1,162 @@
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, get_cap, Answer, Day, ParseError};
+
+fn parse_instruction(s: &str, stacks_count: usize) -> Result<Instruction, ParseError> {
+    let caps = utils::get_caps(r"move (\d+) from (\d+) to (\d+)", s)?;
+    let instruction = Instruction {
+        count: get_cap(&caps, 1)?,
+        from: get_cap(&caps, 2)?,
+        to: get_cap(&caps, 3)?,
+    };
+    for (i, stack) in [(2, instruction.from), (3, instruction.to)] {
+        if !(1..=stacks_count).contains(&stack) {
+            let column = caps.get(i).unwrap().start() + 1;
+            let expected = format!("a stack between 1 and {}", stacks_count);
+            return Err(ParseError::new(expected, stack.to_string()).at_column(column));
+        }
+    }
+    Ok(instruction)
+}
+
+/// Parses a line of crates, in columns of 4 characters: `[A] ` for a crate
+/// and 4 spaces for none, the last column without its trailing space.
+fn parse_elements(s: &str) -> Result<Vec<char>, ParseError> {
+    s.as_bytes()
+        .chunks(4)
+        .enumerate()
+        .map(|(i, column)| match column {
+            [b'[', c, b']'] | [b'[', c, b']', b' '] if c.is_ascii_graphic() => Ok(*c as char),
+            [b' ', b' ', b' '] | [b' ', b' ', b' ', b' '] => Ok(' '),
+            _ => {
+                let found = String::from_utf8_lossy(column);
+                Err(ParseError::new("a crate such as \"[A]\"", found).at_column(i * 4 + 1))
+            }
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Day5, ParseError> {
+    let mut instructions = vec![];
+    let lines = utils::lines(input);
+    let first_line = lines
+        .first()
+        .ok_or_else(|| ParseError::new("a crate line", "end of file").at_line(1))?;
+    let elems_count = (first_line.len() + 1) / 4;
+    let mut stacks = vec![vec![]; elems_count];
+    // Heights of the stacks as the moves are parsed, so that none takes
+    // more crates than there are.
+    let mut heights: Option<Vec<u64>> = None;
+    for (line_number, line) in lines.iter().enumerate() {
+        if line.starts_with("move") {
+            let instruction = parse_instruction(line, elems_count)
+                .map_err(|e| e.at_line(line_number + 1))?;
+            let heights = heights.get_or_insert_with(|| {
+                stacks.iter().map(|s: &Vec<char>| s.len() as u64).collect()
+            });
+            let available = heights[instruction.from - 1];
+            if instruction.count > available {
+                let expected = format!("at most {} crates to move", available);
+                let found = instruction.count.to_string();
+                return Err(ParseError::new(expected, found).at_line(line_number + 1));
+            }
+            heights[instruction.from - 1] -= instruction.count;
+            heights[instruction.to - 1] += instruction.count;
+            instructions.push(instruction)
+        } else if line.contains('[') {
+            if line.len() > first_line.len() || !line.is_ascii() || heights.is_some() {
+                let expected = format!("{} crates per line, before the moves", elems_count);
+                return Err(ParseError::new(expected, line).at_line(line_number + 1));
+            }
+            let elements = parse_elements(line).map_err(|e| e.at_line(line_number + 1))?;
+            for (i, c) in elements.iter().enumerate() {
+                if *c != ' ' {
+                    stacks[i].insert(0, *c);
+                }
+            }
+        }
+    }
+    if stacks.iter().all(Vec::is_empty) {
+        return Err(ParseError::new("at least one crate", "none"));
+    }
+
+    Ok(Day5 {
+        stacks,
+        instructions,
+    })
+}
+
+#[derive(Debug, Clone)]
+struct Instruction {
+    count: u64,
+    from: usize,
+    to: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Day5 {
+    stacks: Vec<Vec<char>>,
+    instructions: Vec<Instruction>,
+}
+
+impl Day5 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        Ok(Box::new(parse_input(input)?))
+    }
+
+    fn execute_instruction_a(&mut self, instruction: &Instruction) {
+        for _ in 0..instruction.count {
+            let elem = self.stacks[instruction.from - 1].pop().unwrap();
+            self.stacks[instruction.to - 1].push(elem);
+        }
+    }
+
+    fn execute_instruction_b(&mut self, instruction: &Instruction) {
+        let len = self.stacks[instruction.from - 1].len();
+        let mut removed = self.stacks[instruction.from - 1]
+            .splice((len - instruction.count as usize)..len, vec![])
+            .collect();
+        self.stacks[instruction.to - 1].append(&mut removed);
+    }
+
+    fn solve(&self, problem: char) -> String {
+        let mut state = self.clone();
+        for instruction in self.instructions.iter() {
+            if problem == 'a' {
+                state.execute_instruction_a(instruction);
+            } else {
+                state.execute_instruction_b(instruction);
+            }
+        }
+        state.stack_top()
+    }
+
+    fn stack_top(&self) -> String {
+        // Emptied stacks have no crate on top, shown as a space.
+        self.stacks
+            .iter()
+            .map(|s| s.last().copied().unwrap_or(' '))
+            .collect()
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 5,
+    title: "Supply Stacks",
+    parts: [
+        "Top crates when moved one at a time",
+        "Top crates when moved all at once",
+    ],
+    params: &[],
+    build: |input, _| Day5::new(input),
+};
+
+impl Day for Day5 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        self.solve('a').into()
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        self.solve('b').into()
+    }
+}
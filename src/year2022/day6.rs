@@ -0,0 +1,57 @@
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{Answer, Day, ParseError};
+use std::collections::HashMap;
+
+fn count_non_zero(h: &HashMap<char, u64>) -> usize {
+    h.values().filter(|v| **v > 0).count()
+}
+
+pub struct Day6 {
+    line: String,
+}
+
+impl Day6 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        let line = input.trim_end().to_string();
+        Ok(Box::new(Day6 { line }))
+    }
+
+    /// The end of the first marker of `count` different characters, `None`
+    /// if the line has none.
+    fn solve(&self, count: usize) -> Option<u64> {
+        let mut m: HashMap<char, u64> = HashMap::new();
+        for (i, c) in self.line.chars().enumerate() {
+            if i >= count {
+                let char_to_remove = self.line.chars().nth(i - count).unwrap();
+                m.entry(char_to_remove).and_modify(|n| *n -= 1);
+            }
+            m.entry(c).and_modify(|f| *f += 1).or_insert(1);
+            if count_non_zero(&m) == count {
+                return Some(i as u64 + 1);
+            }
+        }
+        None
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 6,
+    title: "Tuning Trouble",
+    parts: [
+        "End of the first start-of-packet marker",
+        "End of the first start-of-message marker",
+    ],
+    params: &[],
+    build: |input, _| Day6::new(input),
+};
+
+impl Day for Day6 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        self.solve(4).map_or(Answer::NoSolution, Answer::from)
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        self.solve(14).map_or(Answer::NoSolution, Answer::from)
+    }
+}
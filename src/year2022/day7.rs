@@ -0,0 +1,200 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    str::FromStr,
+};
+
+use crate::params::{ParamSpec, Params};
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, Answer, Day, ParseError};
+
+#[derive(Debug, Clone)]
+enum Command {
+    Cd(String),
+    Ls,
+    Dir(String),
+    File(String, u64),
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        if let Some(arg) = line.strip_prefix("$ cd ") {
+            Ok(Command::Cd(arg.to_string()))
+        } else if line == "$ ls" {
+            Ok(Command::Ls)
+        } else if let Some(dir_name) = line.strip_prefix("dir ") {
+            Ok(Command::Dir(dir_name.to_string()))
+        } else {
+            let (size, file) = utils::split2(line, " ")?;
+            Ok(Command::File(file, size))
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Folder {
+    path: PathBuf,
+    children: HashMap<String, Node>,
+}
+
+impl Folder {
+    fn new(path: PathBuf) -> Folder {
+        Folder {
+            path,
+            children: HashMap::new(),
+        }
+    }
+
+    /// Builds the tree from the commands, each with its line number.
+    fn from(commands: Vec<(usize, Command)>) -> Result<Folder, ParseError> {
+        let mut folder = Folder::new(PathBuf::from("/"));
+        folder.construct(commands)?;
+        Ok(folder)
+    }
+
+    fn construct(
+        &mut self,
+        commands: Vec<(usize, Command)>,
+    ) -> Result<Vec<(usize, Command)>, ParseError> {
+        if commands.is_empty() {
+            return Ok(commands);
+        }
+        let ((line, command), rest_) = commands.split_first().unwrap();
+        let mut rest = rest_.to_vec();
+
+        match command {
+            Command::Cd(arg) if arg == ".." => {
+                return Ok(rest); // to parent dir
+            }
+            Command::Cd(arg) => match self.children.get_mut(arg) {
+                Some(Node::Folder(folder)) => rest = folder.construct(rest)?,
+                Some(Node::File(_)) => {
+                    let error = ParseError::new("a directory", format!("file {}", arg));
+                    return Err(error.at_column(6).at_line(*line));
+                }
+                None => {
+                    let mut folder = Folder::new(self.path.join(arg));
+                    rest = folder.construct(rest)?;
+                    self.children.insert(arg.to_string(), Node::Folder(folder));
+                }
+            },
+            Command::Ls => {
+                // nothing to do
+            }
+            Command::Dir(dir_name) => {
+                if !self.children.contains_key(dir_name) {
+                    self.children.insert(
+                        dir_name.to_string(),
+                        Node::Folder(Folder::new(self.path.join(dir_name))),
+                    );
+                }
+            }
+            Command::File(file, size) => {
+                self.children.insert(file.clone(), Node::File(*size));
+            }
+        }
+
+        self.construct(rest)
+    }
+
+    /// The size of the folder and of every folder in it, `None` if one does
+    /// not fit in 64 bits.
+    fn compute_sizes(&self) -> Option<(u64, HashMap<PathBuf, u64>)> {
+        let mut size: u64 = 0;
+        let mut sizes = HashMap::new();
+        for node in self.children.values() {
+            match node {
+                Node::Folder(f) => {
+                    let (node_size, node_sizes) = f.compute_sizes()?;
+                    size = size.checked_add(node_size)?;
+                    sizes.extend(node_sizes);
+                }
+                Node::File(s) => size = size.checked_add(*s)?,
+            }
+        }
+        sizes.insert(self.path.clone(), size);
+        Some((size, sizes))
+    }
+}
+
+#[derive(Debug)]
+enum Node {
+    Folder(Folder),
+    File(u64),
+}
+
+pub struct Day7 {
+    root_size: u64,
+    folder_sizes: HashMap<PathBuf, u64>,
+    disk_size: u64,
+    needed_space: u64,
+}
+
+impl Day7 {
+    pub fn new(input: &str, params: &Params) -> Result<Box<dyn Day>, ParseError> {
+        let lines = utils::lines(input);
+        let commands = utils::parse_lines_with(&lines, str::parse)?;
+        let commands = commands.into_iter().enumerate().map(|(i, c)| (i + 1, c));
+        let root = Folder::from(commands.skip(1).collect())?;
+        let Some((root_size, folder_sizes)) = root.compute_sizes() else {
+            return Err(ParseError::new("a total size of 64 bits", "more"));
+        };
+        Ok(Box::new(Day7 {
+            root_size,
+            folder_sizes,
+            disk_size: params.get("disk_size"),
+            needed_space: params.get("needed_space"),
+        }))
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 7,
+    title: "No Space Left On Device",
+    parts: [
+        "Total size of the directories under 100000",
+        "Size of the smallest directory to delete",
+    ],
+    params: &[
+        ParamSpec {
+            name: "disk_size",
+            default: 70_000_000,
+            min: 0,
+            max: i64::MAX,
+            description: "Total disk space",
+        },
+        ParamSpec {
+            name: "needed_space",
+            default: 30_000_000,
+            min: 0,
+            max: i64::MAX,
+            description: "Unused space needed for the update",
+        },
+    ],
+    build: Day7::new,
+};
+
+impl Day for Day7 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        self.folder_sizes
+            .values()
+            .filter(|s| **s < 100_000)
+            .sum::<u64>()
+            .into()
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        let unused_space = self.disk_size.saturating_sub(self.root_size);
+        let to_free = self.needed_space.saturating_sub(unused_space);
+        // Not even deleting the root frees enough space when the needed space
+        // is larger than the disk.
+        self.folder_sizes
+            .values()
+            .filter(|s| **s >= to_free)
+            .min()
+            .map_or(Answer::NoSolution, |size| (*size).into())
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::HashSet;
+use crate::grid::{Grid, Point};
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils;
+use crate::utils::{Answer, Day, ParseError};
+
+pub struct Day8 {
+    grid: Grid<i64>,
+}
+
+impl Day8 {
+    pub fn new(input: &str) -> Result<Box<dyn Day>, ParseError> {
+        let lines = utils::lines(input);
+        if lines.is_empty() {
+            return Err(ParseError::new("a line of trees", "end of file").at_line(1));
+        }
+        let width = lines[0].chars().count();
+        if let Some(i) = lines.iter().position(|line| line.chars().count() != width) {
+            let expected = format!("{} trees, as many as on the first line", width);
+            let found = format!("{} trees", lines[i].chars().count());
+            return Err(ParseError::new(expected, found).at_line(i + 1));
+        }
+        Self::from_grid(Grid::from(&lines)?)
+    }
+
+    /// Solves the trees of `grid`, which must hold one on every point of
+    /// its rectangle.
+    pub fn from_grid(grid: Grid<i64>) -> Result<Box<dyn Day>, ParseError> {
+        if grid.is_empty() {
+            return Err(ParseError::new("a tree", "none"));
+        }
+        let size = grid.width * grid.height;
+        if grid.len() as u64 != size {
+            let expected = format!("{} trees, filling the {}x{} grid", size, grid.width, grid.height);
+            return Err(ParseError::new(expected, format!("{} trees", grid.len())));
+        }
+        Ok(Box::new(Self { grid }))
+    }
+
+    /// Adds the trees visible from the start of `line` to `visible`.
+    fn compute_visible<T>(&self, visible: &mut HashSet<Point>, line: T)
+    where
+        T: IntoIterator<Item = Point>,
+    {
+        let mut max = -1;
+        for point in line {
+            let cell = self.grid.get(&point).unwrap();
+            if *cell > max {
+                visible.insert(point);
+                max = *cell;
+            }
+        }
+    }
+
+    fn compute_scenic_score_unidirectional(
+        &self,
+        mut point: Point,
+        get_next: fn(Point) -> Point,
+    ) -> u64 {
+        let height = self.grid.get(&point).unwrap();
+        let mut score = 0;
+        let (max_x, max_y) = (self.grid.width - 1, self.grid.height - 1);
+        loop {
+            score += 1;
+            point = get_next(point);
+            let current_height = self.grid.get(&point);
+            if point.x == 0
+                || point.y == 0
+                || point.x >= max_x as i64
+                || point.y >= max_y as i64
+                || current_height.unwrap() >= height
+            {
+                break score;
+            }
+        }
+    }
+
+    fn compute_scenic_score(&self, point: Point) -> u64 {
+        let score_right =
+            self.compute_scenic_score_unidirectional(point, |p| Point::new(p.x + 1, p.y));
+        let score_left =
+            self.compute_scenic_score_unidirectional(point, |p| Point::new(p.x - 1, p.y));
+        let score_up =
+            self.compute_scenic_score_unidirectional(point, |p| Point::new(p.x, p.y - 1));
+        let score_down =
+            self.compute_scenic_score_unidirectional(point, |p| Point::new(p.x, p.y + 1));
+        score_up * score_left * score_right * score_down
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 8,
+    title: "Treetop Tree House",
+    parts: [
+        "Trees visible from outside the grid",
+        "Highest scenic score",
+    ],
+    params: &[],
+    build: |input, _| Day8::new(input),
+};
+
+impl Day for Day8 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        let mut visible = HashSet::new();
+        let (width, height) = (self.grid.width as i64, self.grid.height as i64);
+        for y in 0..height {
+            let row = (0..width).map(|x| Point::new(x, y));
+            self.compute_visible(&mut visible, row.clone());
+            self.compute_visible(&mut visible, row.rev());
+        }
+        for x in 0..width {
+            let column = (0..height).map(|y| Point::new(x, y));
+            self.compute_visible(&mut visible, column.clone());
+            self.compute_visible(&mut visible, column.rev());
+        }
+        (visible.len() as u64).into()
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        self.grid
+            .iter()
+            .map(|(point, _)| point)
+            .filter(|point| {
+                let (max_x, max_y) = (self.grid.width as i64 - 1, self.grid.height as i64 - 1);
+                (1..max_x).contains(&point.x) && (1..max_y).contains(&point.y)
+            })
+            .map(|point| self.compute_scenic_score(point))
+            .max()
+            // Trees on the edges, the only ones of small grids, score 0.
+            .unwrap_or(0)
+            .into()
+    }
+}
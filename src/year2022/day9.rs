@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+
+use crate::params::{ParamSpec, Params};
+use crate::progress::Progress;
+use crate::registry::DayInfo;
+use crate::utils::{self, Answer, Day, ParseError};
+
+pub struct Instruction {
+    direction: char,
+    distance: i64,
+}
+
+pub struct Day9 {
+    instructions: Vec<Instruction>,
+    knots_a: usize,
+    knots_b: usize,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Point {
+    x: i64,
+    y: i64,
+}
+
+impl Point {
+    fn move_by(&mut self, direction: char, distance: i64) {
+        match direction {
+            'U' => self.y += distance,
+            'D' => self.y -= distance,
+            'R' => self.x += distance,
+            'L' => self.x -= distance,
+            _ => panic!("Unknown direction"),
+        }
+    }
+
+    fn follow(&mut self, head: &Point) {
+        if (self.x - head.x).abs() <= 1 && (self.y - head.y).abs() <= 1 {
+            return;
+        }
+        self.x += (head.x - self.x).signum();
+        self.y += (head.y - self.y).signum();
+    }
+}
+
+impl Day9 {
+    pub fn new(input: &str, params: &Params) -> Result<Box<dyn Day>, ParseError> {
+        let lines = utils::lines(input);
+        let instructions = utils::parse_lines_with(&lines, |line| {
+            let (direction, distance) = utils::split2(line, " ")?;
+            if !"UDRL".contains(direction) {
+                return Err(ParseError::new("U, D, R or L", direction).at_column(1));
+            }
+            Ok(Instruction {
+                direction,
+                distance,
+            })
+        })?;
+
+        Ok(Box::new(Self {
+            instructions,
+            knots_a: params.get("knots_a"),
+            knots_b: params.get("knots_b"),
+        }))
+    }
+
+    fn solve(&self, knots_count: usize) -> u64 {
+        let mut seen = HashSet::new();
+        let mut knots = vec![Point { x: 0, y: 0 }; knots_count];
+        for instruction in &self.instructions {
+            for _ in 0..instruction.distance {
+                knots[0].move_by(instruction.direction, 1);
+                for i in 1..knots.len() {
+                    let head = knots[i - 1];
+                    knots[i].follow(&head);
+                }
+                seen.insert(*knots.last().unwrap());
+            }
+        }
+        seen.len() as u64
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    day: 9,
+    title: "Rope Bridge",
+    parts: [
+        "Positions visited by the tail of the short rope",
+        "Positions visited by the tail of the long rope",
+    ],
+    params: &[
+        ParamSpec {
+            name: "knots_a",
+            default: 2,
+            min: 1,
+            max: 1_000,
+            description: "Knots of the rope in part A",
+        },
+        ParamSpec {
+            name: "knots_b",
+            default: 10,
+            min: 1,
+            max: 1_000,
+            description: "Knots of the rope in part B",
+        },
+    ],
+    build: Day9::new,
+};
+
+impl Day for Day9 {
+    fn solve_a(&self, _progress: &Progress) -> Answer {
+        self.solve(self.knots_a).into()
+    }
+
+    fn solve_b(&self, _progress: &Progress) -> Answer {
+        self.solve(self.knots_b).into()
+    }
+}
@@ -0,0 +1,225 @@
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::registry::DayInfo;
+
+pub const DAYS: &[DayInfo] = &[
+    day1::INFO,
+    day2::INFO,
+    day3::INFO,
+    day4::INFO,
+    day5::INFO,
+    day6::INFO,
+    day7::INFO,
+    day8::INFO,
+    day9::INFO,
+    day10::INFO,
+    day11::INFO,
+    day12::INFO,
+    day13::INFO,
+    day14::INFO,
+    day15::INFO,
+    day16::INFO,
+    day17::INFO,
+    day18::INFO,
+    day19::INFO,
+    day20::INFO,
+    day21::INFO,
+    day22::INFO,
+    day23::INFO,
+    day24::INFO,
+    day25::INFO,
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::progress::Progress;
+    use crate::registry;
+    use crate::utils::Answer;
+    use crate::Error;
+
+    /// The examples of the puzzle statements, with their answers.
+    const EXAMPLES: &[(u8, &str, &str, &str)] = &[
+        (2, "A Y\nB X\nC Z\n", "15", "12"),
+        (4, "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n", "2", "4"),
+        (6, "mjqjpqmgbljsphdztnvjfqwrcgmjb\n", "7", "19"),
+        (6, "abcd\n", "4", "no solution"),
+        (8, "30373\n25512\n65332\n33549\n35390\n", "21", "8"),
+        (8, "30373\n25512\n65332\n", "14", "2"),
+        (12, "SbE\n", "no solution", "no solution"),
+        (
+            14,
+            "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9\n",
+            "24",
+            "93",
+        ),
+        (20, "1\n2\n-3\n3\n-2\n0\n4\n", "3", "1623178306"),
+        (20, "1\n-99999999999\n0\n", "-99999999998", "-81158915298376821694"),
+        (24, "#.#\n#>#\n#.#\n", "no solution", "no solution"),
+    ];
+
+    #[test]
+    fn solve_examples() {
+        let progress = Progress::disabled();
+        for (day, input, a, b) in EXAMPLES {
+            let solver = registry::find(2022, *day).unwrap().parse(input).unwrap();
+            let (answer_a, answer_b) = (solver.solve_a(&progress), solver.solve_b(&progress));
+            assert_eq!(answer_a.to_string(), *a, "day {} part a", day);
+            assert_eq!(answer_b.to_string(), *b, "day {} part b", day);
+        }
+    }
+
+    /// Root's difference can grow or shrink with `humn`, and divisions
+    /// rounding down let several numbers pass the test, of which only the
+    /// exact one counts.
+    #[test]
+    fn humn_either_way() {
+        let cases = [
+            ("root: aaaa + bbbb\naaaa: humn / cccc\ncccc: 3\nbbbb: 5\nhumn: 0\n", 15),
+            ("root: aaaa + bbbb\naaaa: cccc - humn\ncccc: 100\nbbbb: 58\nhumn: 0\n", 42),
+        ];
+        let info = registry::find(2022, 21).unwrap();
+        for (input, humn) in cases {
+            let solver = info.parse(input).unwrap();
+            let answer = solver.solve_b(&Progress::disabled());
+            assert_eq!(answer, Answer::Integer(humn), "{:?}", input);
+        }
+        // The example, where root's difference grows with `humn`.
+        let solver = info.open("data/2022/day21-test.txt", &[]).unwrap();
+        assert_eq!(solver.solve_b(&Progress::disabled()), Answer::Integer(301));
+    }
+
+    /// The cube is folded from the map, whatever its net and the size of
+    /// its faces.
+    #[test]
+    fn fold_cubes() {
+        let info = registry::find(2022, 22).unwrap();
+        for (name, password) in [("day22-test", 5031), ("day22", 126017)] {
+            let solver = info.open(format!("data/2022/{}.txt", name), &[]).unwrap();
+            assert_eq!(solver.solve_b(&Progress::disabled()), Answer::Integer(password));
+        }
+        let solver = info.parse("...\n...\n\n5\n").unwrap();
+        assert_eq!(solver.solve_b(&Progress::disabled()), Answer::NoSolution);
+    }
+
+    /// Part B used to answer 348 or so, depending on the order of a
+    /// `HashMap`, instead of 345.
+    #[test]
+    fn shortest_hike() {
+        let info = registry::find(2022, 12).unwrap();
+        for _ in 0..5 {
+            let solver = info.open("data/2022/day12.txt", &[]).unwrap();
+            assert_eq!(solver.solve_b(&Progress::disabled()), Answer::Integer(345));
+        }
+        let solver = info.open("data/2022/day12-test.txt", &[]).unwrap();
+        assert_eq!(solver.solve_b(&Progress::disabled()), Answer::Integer(29));
+    }
+
+    #[test]
+    fn params_out_of_reach() {
+        let progress = Progress::disabled();
+        let input = std::fs::read_to_string("data/2022/day15-test.txt").unwrap();
+        let params = ["row=99999999".parse().unwrap()];
+        let info = registry::find(2022, 15).unwrap();
+        let solver = info.parse_with(&input, &params).unwrap();
+        assert_eq!(solver.solve_a(&progress), Answer::Integer(0));
+        let input = std::fs::read_to_string("data/2022/day7-test.txt").unwrap();
+        let params = ["needed_space=999999999999".parse().unwrap()];
+        let solver = registry::find(2022, 7).unwrap().parse_with(&input, &params).unwrap();
+        assert_eq!(solver.solve_b(&progress), Answer::NoSolution);
+        let input = std::fs::read_to_string("data/2022/day19-test.txt").unwrap();
+        let params = ["blueprints_b=3".parse().unwrap()];
+        let solver = registry::find(2022, 19).unwrap().parse_with(&input, &params).unwrap();
+        assert_eq!(solver.solve_b(&progress), Answer::NoSolution);
+        let input = "1\n2\n-3\n3\n-2\n0\n10000000\n";
+        let params = ["key=1000000000000".parse().unwrap()];
+        let solver = registry::find(2022, 20).unwrap().parse_with(input, &params).unwrap();
+        assert_eq!(solver.solve_b(&progress), Answer::Integer(3_000_000_000_000));
+    }
+
+    #[test]
+    fn reject_inputs_that_would_panic() {
+        let monkeys = std::fs::read_to_string("data/2022/day11-test.txt").unwrap();
+        let one_monkey = monkeys.lines().take(6).collect::<Vec<_>>().join("\n");
+        let cases = [
+            (3, "ab\ncd\nef\ngh\n", 5),
+            (3, "abc\n", 1),
+            (5, " 1   2 \n", 0),
+            (5, "[N] [\n 1   2 \n", 1),
+            (5, "    [D]\n[N] [C]\n 1   2 \n\nmove 3 from 1 to 2\n", 5),
+            (7, "$ cd /\n$ ls\n123 a\n$ cd a\n", 4),
+            (7, "$ cd /\n$ ls\n18446744073709551615 a\n1 b\n", 0),
+            (8, "", 1),
+            (8, "123\n45\n789\n", 2),
+            (11, &monkeys.replace("by 23", "by 0"), 4),
+            (11, &monkeys.replace("monkey 3", "monkey 9"), 6),
+            (11, &one_monkey, 0),
+            (11, &monkeys.replace("by 23", "by 9999999999").replace("by 19", "by 9999999999"), 0),
+            (13, "[1]\n[2]\n\n[3]\n", 5),
+            (13, "[99999999999999999999]\n[1]\n", 1),
+            (14, "", 0),
+            (17, "\n", 1),
+            (20, "0\n", 0),
+            (21, "root: aaaa + bbbb\naaaa: 1\nhumn: 5\n", 1),
+            (21, "root: aaaa + bbbb\naaaa: 1\nbbbb: 2\n", 0),
+            (21, "root: aaaa + humn\naaaa: aaaa * humn\nhumn: 5\n", 2),
+            (22, "  ##\n#...\n\n10R5\n", 1),
+            (22, "..\n..\n\n99999999999999999999R1\n", 4),
+            (23, "...\n", 0),
+            (24, "#.#\n#.#\n", 0),
+            (24, "#>#\n#.#\n#.#\n", 1),
+            (24, "#.#\n#..\n#.#\n", 2),
+            (24, "#.##\n#..\n#.##\n", 2),
+        ];
+        for (day, input, line) in cases {
+            let err = registry::find(2022, day).unwrap().parse(input).err();
+            let Some(Error::Parse(e)) = err else {
+                panic!("day {}: {:?} was accepted", day, input);
+            };
+            assert_eq!(e.line.unwrap_or(0), line, "day {}: {}", day, e);
+        }
+    }
+
+    /// Worry levels of 64 bits used to overflow when squared.
+    #[test]
+    fn huge_worry_levels() {
+        let progress = Progress::disabled();
+        let monkeys = std::fs::read_to_string("data/2022/day11-test.txt").unwrap();
+        let monkeys = monkeys.replace("79, 60, 97", "99999999999, 60, 97");
+        let solver = registry::find(2022, 11).unwrap().parse(&monkeys).unwrap();
+        for answer in [solver.solve_a(&progress), solver.solve_b(&progress)] {
+            assert!(matches!(answer, Answer::Integer(_)), "{:?}", answer);
+        }
+    }
+
+    #[test]
+    fn snafu_example() {
+        let input = "1=-0-2\n12111\n2=0=\n21\n2=01\n111\n20012\n112\n1=-1=\n1-12\n12\n1=\n122\n";
+        let solver = registry::find(2022, 25).unwrap().parse(input).unwrap();
+        let answer = solver.solve_a(&Progress::disabled());
+        assert_eq!(answer, Answer::Text("2=-1=0".to_owned()));
+    }
+}